@@ -1,160 +1,1005 @@
+use std::cell::OnceCell;
 use std::collections::HashMap;
 use std::env::{self, current_exe};
+use std::fmt::Display;
 use std::fs::File;
-use std::io::{BufRead, BufReader, ErrorKind, Read, Seek, Write, stderr, stdin};
+use std::io::{self, ErrorKind, Read, Write, stderr, stdin};
 use std::path::Path;
 use std::path::PathBuf;
-use std::sync::Arc;
-use std::time::Duration;
+use std::rc::Rc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use anyhow::{Context, bail};
-use reqwest::header::HeaderMap;
+use anyhow::Context;
 
+use crate::crypt::{self, Cipher};
+use crate::error::{CheckerError, Result};
+use crate::input::Input;
+use crate::leaderboard::{self, Leaderboard};
+use crate::param::{self, Run};
+use crate::release;
+use crate::shared::{SharedInput, SharedInputs};
+use crate::source::{AdventOfCode, PuzzleSource};
+use crate::stats::{self, DayStats};
+use crate::token::{self, TokenSource};
+use crate::wait::{self, Backoff, Clock, SystemClock};
 use crate::{Answer, Day, OutputType, Part};
 
-const URL_BASE: &str = "https://adventofcode.com";
-
-fn get_client(session_key: &str) -> anyhow::Result<reqwest::blocking::Client> {
-    let jar = reqwest::cookie::Jar::default();
-    jar.add_cookie_str(&format!("session={session_key}"), &URL_BASE.parse()?);
-    let mut headers = HeaderMap::new();
-    headers.insert(
-        reqwest::header::USER_AGENT,
-        "github.com/etwyniel/aoc-framework by etwyniel@gmail.com".parse()?,
-    );
-    let client = reqwest::blocking::Client::builder()
-        .cookie_provider(Arc::new(jar))
-        .default_headers(headers)
-        .build()?;
-    Ok(client)
+pub(crate) const URL_BASE: &str = "https://adventofcode.com";
+
+/// `inputs` directory next to the crate when run through cargo, next to the binary otherwise
+pub(crate) fn default_inputs_root() -> PathBuf {
+    if let Ok(dir) = std::env::var("CARGO_MANIFEST_DIR") {
+        // if run as `cargo run`, have inputs directory next to src directory
+        PathBuf::from(dir)
+    } else {
+        // otherwise have input directory next to binary
+        current_exe().unwrap().parent().unwrap().to_owned()
+    }
+    .join("inputs")
+}
+
+/// Whether the boolean setting `name` is turned on: set, non-empty and neither `0` nor `false`
+pub(crate) fn env_flag(name: &str) -> bool {
+    env::var(name).is_ok_and(|v| !v.is_empty() && v != "0" && v != "false")
 }
 
 pub struct Checker {
+    inputs_root: PathBuf,
     inputs_dir: PathBuf,
-    client: Option<reqwest::blocking::Client>,
-    filters: Vec<i8>,
+    profile: Option<String>,
+    aoc: Rc<AdventOfCode>,
+    source: Rc<dyn PuzzleSource>,
+    filters: HashMap<u8, i8>,
+    default_filter: i8,
+    cross_check: bool,
+    custom_input: Option<CustomInput>,
+    /// Contents of `custom_input`, read on first use so that every part runs on the same input
+    custom_text: OnceCell<String>,
+    expected: HashMap<u8, Answer>,
+    cipher: Option<Cipher>,
+    hash_answers: bool,
+    shared: SharedInputs,
 }
 
-impl Checker {
-    pub fn new(session_key: Option<String>, filter: &str) -> anyhow::Result<Self> {
-        let inputs_dir = if let Ok(dir) = std::env::var("CARGO_MANIFEST_DIR") {
-            // if run as `cargo run`, have inputs directory next to src directory
-            PathBuf::from(dir)
-        } else {
-            // otherwise have input directory next to binary
-            current_exe().unwrap().parent().unwrap().to_owned()
+/// Input to run a part on instead of the cached puzzle input
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CustomInput {
+    File(PathBuf),
+    Stdin,
+}
+
+impl CustomInput {
+    /// `-` designates stdin, anything else a path
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "-" => CustomInput::Stdin,
+            path => CustomInput::File(PathBuf::from(path)),
         }
-        .join("inputs");
-        if !inputs_dir.is_dir() {
-            std::fs::create_dir(&inputs_dir)?;
+    }
+
+    fn read(&self) -> Result<String> {
+        let mut input = String::new();
+        match self {
+            CustomInput::File(path) => {
+                File::open(path)
+                    .and_then(|mut file| file.read_to_string(&mut input))
+                    .map_err(CheckerError::file(path))?;
+            }
+            CustomInput::Stdin => {
+                stdin().read_to_string(&mut input)?;
+            }
         }
-        let client = match session_key {
-            Some(session_key) => Some(get_client(&session_key)?),
-            None => {
-                eprintln!("Could not find AOC_TOKEN in env");
-                None
+        Ok(input)
+    }
+}
+
+/// Expected answers of a custom input, one per part as in `1:42,2:17`
+fn parse_expected(s: &str) -> Result<HashMap<u8, Answer>> {
+    s.split(',')
+        .filter(|entry| !entry.trim().is_empty())
+        .map(|entry| {
+            let (part, answer) = entry
+                .split_once(':')
+                .ok_or_else(|| CheckerError::InvalidExpected(entry.to_string()))?;
+            let part = part
+                .trim()
+                .parse()
+                .map_err(|_| CheckerError::InvalidExpected(entry.to_string()))?;
+            Ok((part, answer.parse().unwrap()))
+        })
+        .collect()
+}
+
+impl Display for CustomInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CustomInput::File(path) => path.display().fmt(f),
+            CustomInput::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+impl Checker {
+    /// Create a checker for the profile named by the `AOC_PROFILE` environment variable, or the
+    /// default profile if unset. If `session_key` is `None`, the token is looked up with
+    /// [`token::find_token`].
+    pub fn new(session_key: Option<String>, filter: &str) -> Result<Self> {
+        let profile = env::var("AOC_PROFILE").ok().filter(|p| !p.trim().is_empty());
+        Self::with_profile(session_key, profile.as_deref(), filter)
+    }
+
+    /// Create a checker for a named account. Each profile has its own inputs and answers in
+    /// `inputs/{profile}`.
+    pub fn with_profile(
+        session_key: Option<String>,
+        profile: Option<&str>,
+        filter: &str,
+    ) -> Result<Self> {
+        Self::in_dir(session_key, profile, default_inputs_root(), filter)
+    }
+
+    /// Create a checker storing inputs and answers in `inputs_root` instead of the `inputs`
+    /// directory next to the crate or binary
+    pub fn in_dir(
+        session_key: Option<String>,
+        profile: Option<&str>,
+        inputs_root: PathBuf,
+        filter: &str,
+    ) -> Result<Self> {
+        let inputs_dir = match profile {
+            Some(profile) if !token::is_valid_profile(profile) => {
+                return Err(CheckerError::InvalidProfile(profile.to_string()));
             }
+            Some(profile) => inputs_root.join(profile),
+            None => inputs_root.clone(),
+        };
+        if !inputs_dir.is_dir() {
+            std::fs::create_dir_all(&inputs_dir)
+                .map_err(CheckerError::answer_store(&inputs_dir))?;
+        }
+        let token = match session_key {
+            Some(session_key) => Some((session_key, TokenSource::Argument)),
+            None => token::find_token(profile),
         };
+        if token.is_none() {
+            match (profile, token::token_file(profile)) {
+                (Some(profile), Some(path)) => eprintln!(
+                    "Could not find a token for profile {profile} (tried {} in env and .env, {})",
+                    token::var_name(Some(profile)),
+                    path.display()
+                ),
+                (_, Some(path)) => eprintln!(
+                    "Could not find AOC_TOKEN in env or .env, or a token in {}",
+                    path.display()
+                ),
+                _ => eprintln!("Could not find AOC_TOKEN in env or .env"),
+            }
+        }
+        let aoc = Rc::new(AdventOfCode::new(token)?);
         let default_filter = if filter.trim().is_empty() { 0 } else { -1 };
-        let mut filters = vec![default_filter; 25];
+        let mut filters = HashMap::new();
         for flt in filter.split(',') {
             if flt.is_empty() {
                 continue;
             }
-            let mut it = flt.split('.').map(|s| s.parse::<i8>());
+            let invalid = || CheckerError::InvalidFilter(flt.to_string());
+            let mut it = flt
+                .split('.')
+                .map(|s| s.parse::<i8>().map_err(|_| invalid()));
 
             let (day, part) = match (it.next(), it.next()) {
                 (Some(day), Some(part)) => (day?, part?),
                 (Some(day), None) => (day?, 0),
                 _ => continue,
             };
-            filters[day as usize - 1] = part;
+            if day < 1 {
+                return Err(invalid());
+            }
+            filters.insert(day as u8, part);
         }
+        let cross_check = env_flag("AOC_CROSS_CHECK");
+        let custom_input = env::var("AOC_INPUT")
+            .ok()
+            .filter(|input| !input.is_empty())
+            .map(|input| CustomInput::parse(&input));
+        let expected = match env::var("AOC_EXPECTED") {
+            Ok(expected) => parse_expected(&expected)?,
+            Err(_) => HashMap::new(),
+        };
         Ok(Checker {
-            inputs_dir,
-            client: client,
+            inputs_root,
+            profile: profile.map(str::to_owned),
+            source: aoc.clone(),
+            aoc,
             filters,
+            default_filter,
+            cross_check,
+            custom_input,
+            expected,
+            cipher: token::find_encryption_key()
+                .map(|key| Cipher::new(&key))
+                .transpose()?,
+            hash_answers: env_flag("AOC_HASH_ANSWERS"),
+            ..Checker::offline(inputs_dir)
         })
     }
+
+    /// Checker of the inputs and answers already in `inputs_dir`, without a token, encryption key
+    /// or settings from the environment. Nothing is downloaded and no directory is created.
+    pub fn offline(inputs_dir: PathBuf) -> Self {
+        let aoc = Rc::new(AdventOfCode::new(None).expect("no client without a token"));
+        Checker {
+            inputs_root: inputs_dir.clone(),
+            inputs_dir,
+            profile: None,
+            source: aoc.clone(),
+            aoc,
+            filters: HashMap::new(),
+            default_filter: 0,
+            cross_check: false,
+            custom_input: None,
+            custom_text: OnceCell::new(),
+            expected: HashMap::new(),
+            cipher: None,
+            hash_answers: false,
+            shared: SharedInputs::default(),
+        }
+    }
+
+    pub fn profile(&self) -> Option<&str> {
+        self.profile.as_deref()
+    }
+
+    /// Inputs directories of all known accounts, the default one first followed by each profile.
+    /// Only subdirectories holding at least one input count as profiles.
+    pub fn profile_dirs(&self) -> Vec<(Option<String>, PathBuf)> {
+        let mut dirs = vec![(None, self.inputs_root.clone())];
+        let Ok(entries) = std::fs::read_dir(&self.inputs_root) else {
+            return dirs;
+        };
+        let has_inputs = |dir: &Path| {
+            std::fs::read_dir(dir).is_ok_and(|mut entries| {
+                entries.any(|entry| {
+                    entry.is_ok_and(|entry| entry.path().extension().is_some_and(|ext| ext == "in"))
+                })
+            })
+        };
+        let mut profiles = entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_dir() && has_inputs(&entry.path()))
+            .filter_map(|entry| Some((entry.file_name().into_string().ok()?, entry.path())))
+            .collect::<Vec<_>>();
+        profiles.sort();
+        dirs.extend(
+            profiles
+                .into_iter()
+                .map(|(profile, path)| (Some(profile), path)),
+        );
+        dirs
+    }
+
+    /// Run every part against the inputs of all accounts instead of only the current one
+    pub fn cross_check_all(mut self, cross_check: bool) -> Self {
+        self.cross_check = cross_check;
+        self
+    }
+
+    /// Run parts on `input` instead of the puzzle input, read once and shared by every part. The
+    /// results are only checked against answers given with [`Checker::with_expected`]. Combine
+    /// with a filter to select the day and part.
+    pub fn with_input(mut self, input: CustomInput) -> Self {
+        self.custom_input = Some(input);
+        self.custom_text = OnceCell::new();
+        self
+    }
+
+    /// Expected answer of `part` on the custom input
+    pub fn with_expected(mut self, part: u8, answer: Answer) -> Self {
+        self.expected.insert(part, answer);
+        self
+    }
+
+    /// Contents of the custom input, read the first time a part needs it
+    fn custom_text(&self) -> Result<Option<&str>> {
+        let Some(input) = &self.custom_input else {
+            return Ok(None);
+        };
+        if self.custom_text.get().is_none() {
+            _ = self.custom_text.set(input.read()?);
+        }
+        Ok(self.custom_text.get().map(String::as_str))
+    }
+
+    /// Get puzzles from `source` instead of adventofcode.com. The leaderboard, stats and sync
+    /// features still use the adventofcode.com account.
+    pub fn with_source(mut self, source: impl PuzzleSource + 'static) -> Self {
+        self.source = Rc::new(source);
+        self
+    }
+
+    /// Encrypt inputs and answers with `cipher` instead of the key found in `AOC_ENCRYPTION_KEY`,
+    /// or store them in plain text if `None`. Files are decrypted transparently on read, and
+    /// files stored in plain text before stay so until [`Checker::encrypt_stored_files`].
+    pub fn with_cipher(mut self, cipher: Option<Cipher>) -> Self {
+        self.cipher = cipher;
+        self
+    }
+
+    /// Only store a salted hash of correct answers, so that answers files can be committed without
+    /// spoiling them. Incorrect attempts and bounds are still stored as is.
+    pub fn hash_answers(mut self, hash_answers: bool) -> Self {
+        self.hash_answers = hash_answers;
+        self
+    }
+
+    /// Replace every plain correct answer in the answers store with its salted hash, returns the
+    /// number of files rewritten
+    pub fn hash_stored_answers(&self) -> Result<u32> {
+        let entries = std::fs::read_dir(&self.inputs_dir)
+            .map_err(CheckerError::answer_store(&self.inputs_dir))?;
+        let mut count = 0;
+        for path in entries.filter_map(|entry| Some(entry.ok()?.path())) {
+            if path.extension().is_none_or(|ext| ext != "out") {
+                continue;
+            }
+            let saved = self
+                .read_file(&path)
+                .map_err(CheckerError::answer_store(&path))?
+                .unwrap_or_default();
+            let hashed = hash_saved(&saved);
+            if hashed != saved {
+                self.write_file(&path, &hashed)
+                    .map_err(CheckerError::answer_store(&path))?;
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    /// Encrypt every input, answers, puzzle page and leaderboard file of the inputs directory still
+    /// stored in plain text, returns the number of files rewritten. Does nothing without a key.
+    pub fn encrypt_stored_files(&self) -> Result<u32> {
+        if self.cipher.is_none() {
+            return Ok(0);
+        }
+        let entries =
+            std::fs::read_dir(&self.inputs_dir).map_err(CheckerError::file(&self.inputs_dir))?;
+        let mut count = 0;
+        for path in entries.filter_map(|entry| Some(entry.ok()?.path())) {
+            if path
+                .extension()
+                .is_none_or(|ext| !matches!(ext.to_str(), Some("in" | "out" | "html" | "json")))
+            {
+                continue;
+            }
+            let data = std::fs::read(&path).map_err(CheckerError::file(&path))?;
+            if crypt::is_encrypted(&data) {
+                continue;
+            }
+            let contents = String::from_utf8(data).map_err(|e| {
+                CheckerError::file(&path)(io::Error::new(ErrorKind::InvalidData, e))
+            })?;
+            self.write_file(&path, &contents)
+                .map_err(CheckerError::file(&path))?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Read a file of the inputs directory, decrypting it if needed. `None` if it doesn't exist.
+    fn read_file(&self, path: &Path) -> io::Result<Option<String>> {
+        let data = match std::fs::read(path) {
+            Ok(data) => data,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        let data = match &self.cipher {
+            Some(cipher) => cipher.decrypt(data)?,
+            None if crypt::is_encrypted(&data) => {
+                return Err(io::Error::new(
+                    ErrorKind::InvalidData,
+                    "file is encrypted, set AOC_ENCRYPTION_KEY to read it",
+                ));
+            }
+            None => data,
+        };
+        String::from_utf8(data)
+            .map(Some)
+            .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))
+    }
+
+    /// Write a file of the inputs directory, encrypting it if a key is set. The file is written
+    /// to a temporary file first so that an interrupted write never leaves a truncated file.
+    fn write_file(&self, path: &Path, contents: &str) -> io::Result<()> {
+        let data = match &self.cipher {
+            Some(cipher) => cipher.encrypt(contents.as_bytes()),
+            None => contents.as_bytes().to_vec(),
+        };
+        let mut tmp_file = path.as_os_str().to_owned();
+        tmp_file.push(".tmp");
+        std::fs::write(&tmp_file, data)?;
+        std::fs::rename(&tmp_file, path)
+    }
+
+    /// Append a line to a file of the inputs directory. Encrypted files can't be appended to, the
+    /// whole file is rewritten, which is fine for answers files of a few lines.
+    fn append_line(&self, path: &Path, line: &str) -> io::Result<()> {
+        let mut contents = self.read_file(path)?.unwrap_or_default();
+        contents.push_str(line);
+        contents.push('\n');
+        self.write_file(path, &contents)
+    }
+
+    /// Part filter for `day`: 0 runs every part, -1 none, otherwise the only part to run
+    fn filter(&self, day: u8) -> i8 {
+        self.filters
+            .get(&day)
+            .copied()
+            .unwrap_or(self.default_filter)
+    }
+
+    /// Whether the filter selects `part` of `day`, or any part of it if `part` is 0
+    fn is_selected(&self, day: u8, part: u8) -> bool {
+        match self.filter(day) {
+            0 => true,
+            -1 => false,
+            flt => part == 0 || flt == part as i8,
+        }
+    }
+
+    /// Warn that `part` of `day`, or the whole day if `part` is 0, is not implemented, unless the
+    /// filter leaves it out
+    fn warn_not_implemented(&self, day: u8, part: u8) {
+        if !self.is_selected(day, part) {
+            return;
+        }
+        match part {
+            0 => eprintln!("\x1b[1;33mWRN\x1b[0m Day {day} not implemented"),
+            _ => eprintln!("\x1b[1;33mWRN\x1b[0m Day {day} part {part} not implemented"),
+        }
+    }
+
+    fn client(&self) -> Option<&reqwest::blocking::Client> {
+        self.aoc.client()
+    }
+
     pub fn for_part<D: Day, P: Part>(&self) -> PartChecker<'_> {
         PartChecker {
             c: self,
+            source: self.source.clone(),
+            inputs_dir: self.inputs_dir.clone(),
             y: D::YEAR,
             d: D::N,
             p: P::N,
-            runner: |reader| P::run(reader),
+            runner: P::run_with,
             example: D::EXAMPLE,
             example2: D::PART2_EXAMPLE,
             example_result: P::EXAMPLE_RESULT,
-            benchmark_runner: |reader| P::bench(reader),
+            combined_with: P::COMBINED_WITH,
+            benchmark_runner: P::bench,
+        }
+    }
+
+    /// Checker for an adventofcode.com puzzle without a solution, to access its input and answers
+    pub(crate) fn for_puzzle(&self, year: u16, day: u8, part: u8) -> PartChecker<'_> {
+        PartChecker {
+            y: year,
+            d: day,
+            p: part,
+            source: self.aoc.clone(),
+            ..self.for_part::<(), ()>()
+        }
+    }
+
+    /// Record every known correct answer and star of `year` from the puzzle pages, skipping days
+    /// that are already complete locally. Requests are spaced out by a second.
+    pub fn sync(&self, year: u16) -> Result<u32> {
+        let now = SystemTime::now();
+        let mut stars = 0;
+        let mut first = true;
+        for day in 1..=release::day_count(year) {
+            if release::time_until_unlock(year, day, now).is_some() {
+                break;
+            }
+            let checker = self.for_puzzle(year, day, 1);
+            let saved = checker.read_output()?.unwrap_or_default();
+            let saved_stars = stars_in_store(&saved);
+            if saved_stars == 2 {
+                stars += 2;
+                continue;
+            }
+            if !first {
+                std::thread::sleep(Duration::from_secs(1));
+            }
+            first = false;
+            let day_stars = checker.sync()?;
+            eprintln!(
+                "\x1b[1;34m...\x1b[0m {year}-12-{day:02} {}",
+                "*".repeat(day_stars as usize)
+            );
+            stars += day_stars as u32;
+        }
+        Ok(stars)
+    }
+
+    /// Stars recorded in the answers store, by year then day
+    pub fn local_stars(&self) -> std::collections::BTreeMap<u16, [u8; 25]> {
+        let mut stars = std::collections::BTreeMap::new();
+        let Ok(entries) = std::fs::read_dir(&self.inputs_dir) else {
+            return stars;
+        };
+        for entry in entries.filter_map(|entry| entry.ok()) {
+            let name = entry.file_name();
+            let Some((year, day)) = name
+                .to_str()
+                .and_then(|name| name.strip_suffix(".out"))
+                .and_then(|name| name.split_once("-12-"))
+            else {
+                continue;
+            };
+            let (Ok(year), Ok(day)) = (year.parse::<u16>(), day.parse::<u8>()) else {
+                continue;
+            };
+            if !(1..=release::day_count(year)).contains(&day) {
+                continue;
+            }
+            let contents = self
+                .read_file(&entry.path())
+                .ok()
+                .flatten()
+                .unwrap_or_default();
+            stars.entry(year).or_insert([0; 25])[day as usize - 1] = stars_in_store(&contents);
+        }
+        stars
+    }
+
+    /// Fetch the personal stats page of `year`, with the time to solve and rank of each part
+    pub fn personal_stats(&self, year: u16) -> Result<Vec<DayStats>> {
+        let Some(client) = self.client() else {
+            return Err(CheckerError::MissingToken("fetch personal stats"));
+        };
+        let resp = client
+            .get(format!("{URL_BASE}/{year}/leaderboard/self"))
+            .send()?;
+        let status = resp.status();
+        let body = resp.text()?;
+        if !status.is_success() {
+            return Err(CheckerError::HttpStatus { status, body });
+        }
+        Ok(stats::parse_self_page(&body))
+    }
+
+    /// Report of time to solve each part relative to its release, using the personal stats page
+    /// when available and solve times recorded by the checker otherwise
+    pub fn stats(&self, year: u16) -> String {
+        let mut stats = match self.personal_stats(year) {
+            Ok(stats) => stats,
+            Err(err) => {
+                eprintln!(
+                    "\x1b[1;33mWRN\x1b[0m could not fetch personal stats, using local times only: {err}"
+                );
+                Vec::new()
+            }
+        };
+        for day in 1..=release::day_count(year) {
+            let unlock = release::unlock_time(year, day);
+            for part in 1..=2 {
+                let solved = self.for_puzzle(year, day, part).solve_time();
+                if let Some(time) = solved.and_then(|t| t.duration_since(unlock).ok()) {
+                    stats::merge_local(&mut stats, day, part, time);
+                }
+            }
+        }
+        stats::render(year, &stats)
+    }
+
+    /// Render the stars of every year from the answers store, without any request
+    pub fn calendar(&self) -> String {
+        let mut out = String::from("      1234567890123456789012345\n");
+        for (year, days) in self.local_stars() {
+            out.push_str(&format!("{year}  "));
+            for stars in &days[..release::day_count(year) as usize] {
+                out.push_str(match *stars {
+                    2 => "\x1b[1;33m*\x1b[0m",
+                    1 => "\x1b[37m*\x1b[0m",
+                    _ => "\x1b[38;5;8m.\x1b[0m",
+                });
+            }
+            let total = days.iter().map(|&s| s as u32).sum::<u32>();
+            out.push_str(&format!(" {total:>2}*\n"));
         }
+        out
     }
 
     pub fn run_part<D: Day, P: Part>(&self) -> &Self {
-        self.for_part::<D, P>().run_and_display();
+        if P::N == 0 {
+            return self;
+        }
+        if self.custom_input.is_some() {
+            self.for_part::<D, P>().run_custom_and_display();
+        } else if self.cross_check {
+            self.for_part::<D, P>().cross_check_and_display();
+        } else {
+            self.for_part::<D, P>().run_and_display();
+        }
         self
     }
 
     pub fn run<D: Day>(&self) -> &Self {
-        self.run_part::<D, D::Part1>().run_part::<D, D::Part2>()
+        D::run_parts(self);
+        self
+    }
+
+    /// Run a part, or warn that it is not implemented if the filter selects it
+    pub fn run_part_or_warn<D: Day, P: Part>(&self, part: u8) -> &Self {
+        if P::N == 0 {
+            self.warn_not_implemented(D::N, part);
+            return self;
+        }
+        self.run_part::<D, P>()
+    }
+
+    /// Warn about the unlocked days of `year` selected by the filter that are not in `days`
+    pub fn warn_missing_days(&self, year: u16, days: &[u8]) -> &Self {
+        let now = SystemTime::now();
+        for day in 1..=release::day_count(year) {
+            if !days.contains(&day) && release::time_until_unlock(year, day, now).is_none() {
+                self.warn_not_implemented(day, 0);
+            }
+        }
+        self
+    }
+
+    /// Fetch a private leaderboard, using the cached copy if it was fetched less than 15 minutes
+    /// ago. A stale cache is used if the leaderboard can't be fetched.
+    pub fn leaderboard(&self, year: u16, id: u64) -> Result<Leaderboard> {
+        let cache = self
+            .inputs_dir
+            .join(format!("leaderboard-{year}-{id}.json"));
+        let cache_age = std::fs::metadata(&cache)
+            .and_then(|meta| meta.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok());
+        if let Some(age) = cache_age
+            && age < leaderboard::REFRESH_INTERVAL
+        {
+            return self.read_leaderboard(&cache);
+        }
+
+        let fetched = self
+            .client()
+            .ok_or(CheckerError::MissingToken("fetch leaderboard"))
+            .and_then(|client| {
+                let resp = client
+                    .get(format!(
+                        "{URL_BASE}/{year}/leaderboard/private/view/{id}.json"
+                    ))
+                    .send()?;
+                let status = resp.status();
+                let body = resp.text()?;
+                if !status.is_success() {
+                    return Err(CheckerError::HttpStatus { status, body });
+                }
+                Ok(body)
+            })
+            .and_then(|body| Ok((Leaderboard::parse(&body)?, body)));
+        match fetched {
+            Ok((lb, body)) => {
+                self.write_file(&cache, &body)
+                    .map_err(CheckerError::file(&cache))?;
+                Ok(lb)
+            }
+            Err(err) if cache_age.is_some() => {
+                eprintln!(
+                    "\x1b[1;33mWRN\x1b[0m failed to refresh leaderboard, using cached copy: {err}"
+                );
+                // wait a full interval before trying again
+                _ = File::options()
+                    .append(true)
+                    .open(&cache)
+                    .and_then(|file| file.set_modified(SystemTime::now()));
+                self.read_leaderboard(&cache)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Read a cached leaderboard, decrypting it if needed
+    fn read_leaderboard(&self, cache: &Path) -> Result<Leaderboard> {
+        let body = self
+            .read_file(cache)
+            .and_then(|body| body.ok_or_else(|| ErrorKind::NotFound.into()))
+            .map_err(CheckerError::file(cache))?;
+        Leaderboard::parse(&body)
+    }
+
+    pub fn show_leaderboard(&self, year: u16, id: u64) -> &Self {
+        match self.leaderboard(year, id) {
+            Ok(lb) => print!("{}", lb.render()),
+            Err(err) => {
+                eprintln!("\x1b[1;31mERR\x1b[0m leaderboard {year}/{id} => {err}");
+                print_causes(&err);
+            }
+        }
+        self
+    }
+
+    /// Wait for the puzzle to unlock while displaying a countdown, then fetch the input and
+    /// puzzle page as soon as they are available and run the day
+    pub fn wait<D: Day>(&self) -> &Self {
+        self.wait_with::<D>(&SystemClock)
+    }
+
+    pub fn wait_with<D: Day>(&self, clock: &impl Clock) -> &Self {
+        let id = self.source.puzzle_id(D::YEAR, D::N);
+        let unlock = self
+            .source
+            .unlock_time(D::YEAR, D::N)
+            .unwrap_or_else(|| clock.now());
+        wait::wait_until(clock, unlock, |remaining| {
+            eprint!(
+                "\r\x1b[K\x1b[1;34m...\x1b[0m {id} unlocks in {}",
+                release::format_countdown(remaining)
+            );
+            _ = stderr().flush();
+        });
+        eprint!("\r\x1b[K");
+
+        let part = self.for_part::<D, D::Part1>();
+        let fetched = part.prefetch(clock).and_then(|_| part.fetch_puzzle(clock));
+        match fetched {
+            Ok(Some(puzzle)) => eprintln!(
+                "\x1b[1;34m...\x1b[0m {id} puzzle saved to {}",
+                puzzle.display()
+            ),
+            Ok(None) => {}
+            Err(err) => {
+                eprintln!("\x1b[1;31mERR\x1b[0m {id} => {err}");
+                print_causes(&err);
+                return self;
+            }
+        }
+        self.run::<D>()
+    }
+}
+
+/// Check that a downloaded input is an actual puzzle input and not an error page, and normalize
+/// its line endings
+pub(crate) fn validate_input(body: &str) -> Result<String> {
+    let invalid = |reason| Err(CheckerError::InvalidInput(reason));
+    let trimmed = body.trim_start();
+    if trimmed.is_empty() {
+        return invalid("input is empty");
+    }
+    if body.contains("Please don't repeatedly request this endpoint before it unlocks") {
+        return invalid("puzzle is not unlocked yet");
+    }
+    if body.contains("Puzzle inputs differ by user") {
+        return invalid("not logged in, the AOC token may be invalid or expired");
+    }
+    // first 256 characters, slicing on a character boundary
+    let start = match trimmed.char_indices().nth(256) {
+        Some((end, _)) => &trimmed[..end],
+        None => trimmed,
+    };
+    let start = start.to_lowercase();
+    if start.starts_with("<!doctype") || start.starts_with("<html") || start.contains("<head>") {
+        return invalid("received an HTML page instead of an input");
     }
+    Ok(body.replace("\r\n", "\n"))
 }
 
+/// Result of a part, whether it matches the known answers and how long it took to run
+pub type PartResult = (Answer, OutputType, Duration);
+
+/// Correct answer as stored in the answers file
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum KnownAnswer {
+    Plain(String),
+    /// Only a salted hash of the answer is stored, so that it can be committed without spoiling it
+    Hashed {
+        salt: String,
+        hash: String,
+    },
+}
+
+impl KnownAnswer {
+    fn parse_hashed(s: &str) -> Option<Self> {
+        let (salt, hash) = s.split_once(':')?;
+        Some(KnownAnswer::Hashed {
+            salt: salt.to_string(),
+            hash: hash.to_string(),
+        })
+    }
+
+    fn matches(&self, answer: &str) -> bool {
+        match self {
+            KnownAnswer::Plain(known) => known == answer,
+            KnownAnswer::Hashed { salt, hash } => &crypt::hash_answer(salt, answer) == hash,
+        }
+    }
+}
+
+impl Display for KnownAnswer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KnownAnswer::Plain(answer) => answer.fmt(f),
+            KnownAnswer::Hashed { .. } => write!(f, "(hidden, only its hash is stored)"),
+        }
+    }
+}
+
+/// Correct answer, if known, and previous incorrect attempts
+pub(crate) type SavedAnswers = (Option<KnownAnswer>, Vec<(OutputType, String)>);
+
+/// Read the known answers of `part` from the contents of an answers file
+fn parse_saved(saved: &str, part: u8) -> SavedAnswers {
+    let mut correct = None;
+    let incorrect = saved
+        .lines()
+        .filter_map(|ln| {
+            let ln = ln.strip_prefix(&part.to_string())?;
+            let (c, answer) = ln.trim_start().split_at_checked(1)?;
+            let answer = answer.trim().to_string();
+            match c {
+                "=" => {
+                    correct = Some(KnownAnswer::Plain(answer));
+                    None
+                }
+                "#" => {
+                    correct = KnownAnswer::parse_hashed(&answer);
+                    None
+                }
+                ">" => Some((OutputType::TooHigh, answer)),
+                "<" => Some((OutputType::TooLow, answer)),
+                "!" => Some((OutputType::Invalid, answer)),
+                _ => None,
+            }
+        })
+        .collect();
+    (correct, incorrect)
+}
+
+/// Replace the plain correct answers of an answers file with salted hashes
+fn hash_saved(saved: &str) -> String {
+    saved
+        .lines()
+        .map(|ln| match ln.split_once('=') {
+            Some((part, answer)) if part.parse::<u8>().is_ok() => {
+                let salt = crypt::new_salt();
+                let hash = crypt::hash_answer(&salt, answer);
+                format!("{part}#{salt}:{hash}\n")
+            }
+            _ => format!("{ln}\n"),
+        })
+        .collect()
+}
+
+/// Compare a result to the saved answers, returns `None` if the correctness of the result is
+/// unknown
+fn compare_saved(
+    res: &Answer,
+    correct: Option<KnownAnswer>,
+    incorrect: &[(OutputType, String)],
+) -> Option<OutputType> {
+    if res == &Answer::Num(0) || res == &Answer::Str("".into()) {
+        return Some(OutputType::Invalid);
+    }
+
+    let res_str = res.to_string();
+    if let Some(correct) = &correct
+        && correct.matches(&res_str)
+    {
+        return Some(OutputType::Correct);
+    }
+
+    if let Some((ty, _)) = incorrect.iter().find(|(_, prev)| prev == &res_str) {
+        // previously attempted incorrect answer
+        return Some(ty.clone());
+    }
+
+    if let Answer::Num(n) = &res
+        && let Some((ty, _)) = incorrect.iter().find(|(ty, v)| {
+            let Ok(v) = v.parse::<u64>() else {
+                return false;
+            };
+            ty == &OutputType::TooLow && *n < v || ty == &OutputType::TooHigh && *n > v
+        })
+    {
+        return Some(ty.clone());
+    }
+
+    // result is not one of the previous incorrect answers, and not the correct answer
+    correct.map(|correct| OutputType::Incorrect(correct.to_string()))
+}
+
+#[derive(Clone)]
 pub struct PartChecker<'a> {
     c: &'a Checker,
+    source: Rc<dyn PuzzleSource>,
+    inputs_dir: PathBuf,
     y: u16,
     d: u8,
     p: u8,
-    runner: fn(&mut dyn BufRead) -> anyhow::Result<Answer>,
+    runner: fn(&str, &SharedInput, Run) -> anyhow::Result<Answer>,
     example: Option<&'static str>,
     example2: Option<&'static str>,
     example_result: Option<Answer>,
-    benchmark_runner: fn(&mut dyn BufRead) -> Option<Duration>,
+    combined_with: Option<u8>,
+    benchmark_runner: fn(Input<'_>) -> Option<Duration>,
 }
 
 impl<'a> PartChecker<'a> {
-    fn output_file(&self) -> PathBuf {
+    pub(crate) fn output_file(&self) -> PathBuf {
+        let stem = self.source.file_stem(self.y, self.d);
+        self.inputs_dir.join(format!("{stem}.out"))
+    }
+
+    pub(crate) fn input_file(&self) -> PathBuf {
+        let stem = self.source.file_stem(self.y, self.d);
+        self.inputs_dir.join(format!("{stem}.in"))
+    }
+
+    /// Identifier of the part in the output, e.g. `2023-12-01.2`
+    fn id(&self) -> String {
+        format!("{}.{}", self.source.puzzle_id(self.y, self.d), self.p)
+    }
+
+    /// Contents of the answers store of this day, `None` if nothing was saved yet
+    fn read_output(&self) -> Result<Option<String>> {
+        let path = self.output_file();
         self.c
-            .inputs_dir
-            .join(format!("{}-12-{}.out", self.y, self.d))
+            .read_file(&path)
+            .map_err(CheckerError::answer_store(path))
     }
 
-    fn input_file(&self) -> PathBuf {
+    fn append_output(&self, line: &str) -> Result<()> {
+        let path = self.output_file();
         self.c
-            .inputs_dir
-            .join(format!("{}-12-{}.in", self.y, self.d))
-    }
-
-    fn get_saved_answers(&self) -> anyhow::Result<(Option<String>, Vec<(OutputType, String)>)> {
-        let f = BufReader::new(File::open(self.output_file())?);
-        let mut correct = None;
-        let incorrect = f
-            .lines()
-            .filter_map(|ln| ln.ok())
-            .filter_map(|ln| {
-                let ln = ln.strip_prefix(&self.p.to_string())?;
-                let (c, answer) = ln.trim_start().split_at_checked(1)?;
-                let answer = answer.trim().to_string();
-                match c {
-                    "=" => {
-                        correct = Some(answer);
-                        None
-                    }
-                    ">" => Some((OutputType::TooHigh, answer)),
-                    "<" => Some((OutputType::TooLow, answer)),
-                    "!" => Some((OutputType::Invalid, answer)),
-                    _ => None,
-                }
-            })
-            .collect();
-        Ok((correct, incorrect))
+            .append_line(&path, line)
+            .map_err(CheckerError::answer_store(path))
     }
 
-    fn save_answer(&self, answer: &str, ty: &OutputType) -> anyhow::Result<()> {
+    /// Cached input, `None` if it was not downloaded yet
+    pub(crate) fn read_input(&self) -> Result<Option<String>> {
+        let path = self.input_file();
+        self.c.read_file(&path).map_err(CheckerError::file(path))
+    }
+
+    pub(crate) fn write_input(&self, input: &str) -> Result<()> {
+        let path = self.input_file();
+        self.c
+            .write_file(&path, input)
+            .map_err(CheckerError::file(path))
+    }
+
+    fn input(&self) -> Result<String> {
+        self.read_input()?
+            .ok_or_else(|| CheckerError::file(self.input_file())(ErrorKind::NotFound.into()))
+    }
+
+    /// Use the inputs and answers stored in `dir` instead of the checker's inputs directory
+    pub fn with_inputs_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.inputs_dir = dir.into();
+        self
+    }
+
+    /// Read the known correct answer and incorrect attempts for this part, `None` if no answers
+    /// were ever saved for this day
+    pub(crate) fn get_saved_answers(&self) -> Result<Option<SavedAnswers>> {
+        Ok(self.read_output()?.map(|saved| parse_saved(&saved, self.p)))
+    }
+
+    fn save_answer(&self, answer: &str, ty: &OutputType) -> Result<()> {
         self.save_answer_for_part(answer, ty, self.p)
     }
 
-    fn save_answer_for_part(&self, answer: &str, ty: &OutputType, part: u8) -> anyhow::Result<()> {
+    pub(crate) fn save_answer_for_part(
+        &self,
+        answer: &str,
+        ty: &OutputType,
+        part: u8,
+    ) -> Result<()> {
         let c = match ty {
             OutputType::Correct => '=',
             OutputType::TooLow => '<',
@@ -162,31 +1007,27 @@ impl<'a> PartChecker<'a> {
             OutputType::Invalid => '!',
             _ => return Ok(()),
         };
-        let mut f = File::options()
-            .append(true)
-            .create(true)
-            .open(self.output_file())?;
-        writeln!(&mut f, "{}{c}{answer}", part)?;
-        Ok(())
+        if c == '=' && self.c.hash_answers {
+            let salt = crypt::new_salt();
+            let hash = crypt::hash_answer(&salt, answer);
+            return self.append_output(&format!("{part}#{salt}:{hash}"));
+        }
+        self.append_output(&format!("{part}{c}{answer}"))
     }
 
     #[allow(unused)]
-    fn submit_answer(&self, res_str: &str) -> anyhow::Result<OutputType> {
+    fn submit_answer(&self, res_str: &str) -> Result<OutputType> {
         // retrieve http client to submit answer
-        let Some(client) = &self.c.client else {
+        let Some(client) = self.c.client() else {
             // no client (missing token), don't know if answer is correct
             return Ok(OutputType::Unknown);
         };
 
         let y = self.y;
         let d = self.d;
-        let p = self.p;
-        let always_check = env::var("AOC_ALWAYS_CHECK")
-            .map(|v| v != "0" && v != "false")
-            .unwrap_or(false);
-        if !always_check {
+        if !env_flag("AOC_ALWAYS_CHECK") {
             // prompt user whether to submit answer
-            eprintln!("{y}-12-{d}.{p} => {res_str}\nCheck answer? (yes/no): ",);
+            eprintln!("{} => {res_str}\nCheck answer? (yes/no): ", self.id());
             stderr().flush()?;
 
             // read answer
@@ -201,21 +1042,19 @@ impl<'a> PartChecker<'a> {
         let mut form = HashMap::new();
         form.insert("level", self.p.to_string());
         form.insert("answer", res_str.to_string());
-        let mut resp = client
+        let resp = client
             .post(format!("{URL_BASE}/{y}/day/{d}/answer"))
             .form(&form)
-            .send()
-            .context("failed to submit answer")?;
+            .send()?;
 
         // read body to determine if answer was correct
-        let mut resp_body = String::new();
-        resp.read_to_string(&mut resp_body)?;
-        if !resp.status().is_success() {
-            bail!(
-                "request failed with status {}: {}",
-                resp.status(),
-                resp_body,
-            )
+        let status = resp.status();
+        let resp_body = resp.text()?;
+        if !status.is_success() {
+            return Err(CheckerError::HttpStatus {
+                status,
+                body: resp_body,
+            });
         }
         let ty = if !resp_body.contains("not the right answer") {
             OutputType::Correct
@@ -229,94 +1068,97 @@ impl<'a> PartChecker<'a> {
         Ok(ty)
     }
 
-    fn fetch_submitted_answers(&self) -> anyhow::Result<Vec<String>> {
-        let Some(client) = &self.c.client else {
-            return Ok(Vec::new());
-        };
+    fn fetch_day_page(&self) -> Result<Option<String>> {
+        self.source.puzzle_page(self.y, self.d)
+    }
 
-        let mut resp_body = String::new();
-        client
-            .get(format!("{URL_BASE}/{}/day/{}", self.y, self.d))
-            .send()?
-            .read_to_string(&mut resp_body)?;
-
-        // extract answers from html
-        let mut answers = Vec::with_capacity(2);
-        let mut body = resp_body.as_str();
-        while let Some(ndx) = body.find("Your puzzle answer was") {
-            body = &body[ndx..];
-            // find end of opening <code> tag
-            let Some(start) = body.find('>') else {
-                break;
-            };
-            body = &body[(start + 1)..];
-            // find start of closing </code> tag
-            let Some(end) = body.find('<') else { break };
-            let answer = body[..end].trim().to_string();
-            // advance slice
-            body = &body[end..];
-
-            self.save_answer_for_part(
-                &answer,
-                &OutputType::Correct,
-                (answers.len() + 1).min(2) as u8,
-            )?;
-            answers.push(answer);
-        }
-        if answers.is_empty() {
+    /// Save correct answers found on the puzzle page that are not saved yet
+    fn record_answers(&self, answers: &[String], stars: u8) -> Result<()> {
+        let saved = self.read_output()?;
+        let exists = saved.is_some();
+        let saved = saved.unwrap_or_default();
+        for (i, answer) in answers.iter().enumerate() {
+            let part = (i + 1).min(self.source.parts() as usize) as u8;
+            let (known, _) = parse_saved(&saved, part);
+            if !known.is_some_and(|known| known.matches(answer)) {
+                self.save_answer_for_part(answer, &OutputType::Correct, part)?;
+            }
+        }
+        // stars without an answer, e.g. the last star of the year
+        for part in (answers.len() as u8 + 1)..=stars {
+            if !saved.lines().any(|ln| ln == format!("{part}*")) {
+                self.save_star(part)?;
+            }
+        }
+        if answers.is_empty() && stars == 0 && !exists {
             // no answers found in body, create empty outputs file to avoid fetching repeatedly
-            File::create(self.output_file())?;
+            let path = self.output_file();
+            self.c
+                .write_file(&path, "")
+                .map_err(CheckerError::answer_store(path))?;
+        }
+        Ok(())
+    }
+
+    /// Time at which the answer to this part was first confirmed correct
+    pub fn solve_time(&self) -> Option<SystemTime> {
+        let saved = self.read_output().ok()??;
+        saved.lines().find_map(|ln| {
+            let ts = ln.strip_prefix(&format!("{}@", self.p))?;
+            Some(UNIX_EPOCH + Duration::from_secs(ts.trim().parse().ok()?))
+        })
+    }
+
+    fn record_solve_time(&self, time: SystemTime) -> Result<()> {
+        if self.solve_time().is_some() {
+            return Ok(());
         }
+        let ts = time
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        self.append_output(&format!("{}@{ts}", self.p))
+    }
+
+    fn save_star(&self, part: u8) -> Result<()> {
+        self.append_output(&format!("{part}*"))
+    }
+
+    fn fetch_submitted_answers(&self) -> Result<Vec<String>> {
+        let Some((answers, stars)) = self.source.fetch_answers(self.y, self.d)? else {
+            return Ok(Vec::new());
+        };
+        self.record_answers(&answers, stars)?;
         Ok(answers)
     }
 
-    pub fn check_answer(&self, res: &Answer) -> anyhow::Result<OutputType> {
-        let (correct, incorrect) = match self.get_saved_answers() {
-            Err(e)
-                if e.downcast_ref::<std::io::Error>()
-                    .map(|e| e.kind() == ErrorKind::NotFound)
-                    .unwrap_or(false) =>
-            {
+    /// Fetch the puzzle page and record every correct answer and star found on it, returns the
+    /// number of stars
+    fn sync(&self) -> Result<u8> {
+        let Some(page) = self.fetch_day_page()? else {
+            return Err(CheckerError::MissingToken("sync answers"));
+        };
+        let (answers, stars) = parse_day_page(&page);
+        self.record_answers(&answers, stars)?;
+        Ok(stars)
+    }
+
+    pub fn check_answer(&self, res: &Answer) -> Result<OutputType> {
+        let (correct, incorrect) = match self.get_saved_answers()? {
+            Some(output) => output,
+            None => {
                 // no outputs file found, fetch potential existing answers
                 let answers = self.fetch_submitted_answers()?;
                 // use fetched answer for this part
-                (answers.into_iter().nth(self.p as usize - 1), Vec::new())
+                let answer = answers.into_iter().nth(self.p as usize - 1);
+                (answer.map(KnownAnswer::Plain), Vec::new())
             }
-            Err(e) => return Result::Err(e),
-            Ok(output) => output,
         };
 
-        if res == &Answer::Num(0) || res == &Answer::Str("".into()) {
-            return Ok(OutputType::Invalid);
+        if let Some(ty) = compare_saved(res, correct, &incorrect) {
+            return Ok(ty);
         }
-
         let res_str = res.to_string();
-        if let Some(correct) = &correct
-            && &res_str == correct
-        {
-            return Ok(OutputType::Correct);
-        }
-
-        if let Some((ty, _)) = incorrect.iter().find(|(_, prev)| prev == &res_str) {
-            // previously attempted incorrect answer
-            return Ok(ty.clone());
-        }
-
-        if let Answer::Num(n) = &res {
-            if let Some((ty, _)) = incorrect.iter().find(|(ty, v)| {
-                let Ok(v) = v.parse::<u64>() else {
-                    return false;
-                };
-                ty == &OutputType::TooLow && *n < v || ty == &OutputType::TooHigh && *n > v
-            }) {
-                return Ok(ty.clone());
-            }
-        }
-
-        if let Some(correct) = correct {
-            // result is not one of the previous incorrect answers, and not the correct answer
-            return Ok(OutputType::Incorrect(correct));
-        }
 
         if atty::isnt(atty::Stream::Stdout) {
             // can't prompt user, answer correctness is unknown
@@ -324,10 +1166,7 @@ impl<'a> PartChecker<'a> {
         }
 
         // prompt user whether to submit answer
-        eprintln!(
-            "\x1b[38;5;8m???\x1b[0m {}-12-{:02}.{} => {res_str}",
-            self.y, self.d, self.p
-        );
+        eprintln!("\x1b[38;5;8m???\x1b[0m {} => {res_str}", self.id());
         eprint!("\tCorrect answer? [yes (y)/no (n)/too low (l)/too high (h)] ");
         stderr().flush()?;
 
@@ -344,150 +1183,910 @@ impl<'a> PartChecker<'a> {
             _ => return Ok(OutputType::Unknown),
         };
 
-        if let Answer::Num(n) = res {
+        if let Answer::Num(n) = res
+            && (ty == OutputType::TooLow || ty == OutputType::TooHigh)
+        {
             // check if output type is coherent with saved answers
-            if ty == OutputType::TooLow {
-                if let Some((_, lower)) = incorrect.iter().find(|(ty, v)| {
-                    ty == &OutputType::TooHigh && v.parse::<u64>().map(|v| v <= *n).unwrap_or(false)
-                }) {
-                    bail!("Conflicting answers:\n\t{n} is too low, but\n\t{lower} was too high")
-                }
-            } else if ty == OutputType::TooHigh {
-                if let Some((_, higher)) = incorrect.iter().find(|(ty, v)| {
-                    ty == &OutputType::TooLow && v.parse::<u64>().map(|v| v >= *n).unwrap_or(false)
-                }) {
-                    bail!("Conflicting answers:\n\t{n} is too high, but\n\t{higher} was too low")
+            let too_low = ty == OutputType::TooLow;
+            if let Some((_, previous)) = incorrect.iter().find(|(prev_ty, v)| {
+                let Ok(v) = v.parse::<u64>() else {
+                    return false;
+                };
+                if too_low {
+                    prev_ty == &OutputType::TooHigh && v <= *n
+                } else {
+                    prev_ty == &OutputType::TooLow && v >= *n
                 }
+            }) {
+                return Err(CheckerError::ConflictingAnswers {
+                    answer: *n,
+                    too_low,
+                    previous: previous.clone(),
+                });
             }
         }
 
         // save answer, log potential error but continue
-        if let Err(e) = self
-            .save_answer(&res_str, &ty)
-            .context("failed to save answer {res_str}")
+        if let Err(e) = self.save_answer(&res_str, &ty) {
+            eprintln!("failed to save answer {res_str}");
+            print_causes(&e);
+        }
+        if ty == OutputType::Correct
+            && let Err(e) = self.record_solve_time(SystemTime::now())
         {
-            eprintln!("{e}");
-            e.chain()
-                .skip(1)
-                .for_each(|cause| eprintln!("because: {}", cause));
+            eprintln!("failed to record solve time");
+            print_causes(&e);
         }
         Ok(ty)
     }
 
-    fn check(&self, input: &str) -> anyhow::Result<()> {
-        let Some(expected) = &self.example_result else {
-            println!("No example");
-            return Ok(());
+    /// Parsed input and answers shared by the parts of the day run on `input`
+    fn shared(&self, input: &str) -> Rc<SharedInput> {
+        self.c.shared.get(self.y, self.d, input)
+    }
+
+    /// Run the part, sharing the parsed input and the answer with the other parts of the day
+    fn solve(&self, input: &str, shared: &SharedInput, run: Run) -> anyhow::Result<Answer> {
+        let res = (self.runner)(input, shared, run)?;
+        shared.record_answer(self.p, &res);
+        Ok(res)
+    }
+
+    /// Run and time the part, a part computed by an earlier one gets the time of that run
+    fn solve_timed(&self, input: &str, shared: &SharedInput) -> anyhow::Result<(Answer, Duration)> {
+        let start = std::time::Instant::now();
+        let res = self.solve(input, shared, Run::Real)?;
+        let delta = self
+            .combined_with
+            .and_then(|part| shared.time(part))
+            .unwrap_or_else(|| start.elapsed());
+        shared.record_time(self.p, delta);
+        Ok((res, delta))
+    }
+
+    /// Examples stored next to the inputs as `{stem}.example{N}.in`, in order of `N` which may
+    /// have gaps, with the answers of each part on the lines of `{stem}.example{N}.out`.
+    /// Examples without a known answer for this part, empty or `-`, are skipped.
+    fn example_files(&self) -> Result<Vec<(usize, String, Answer)>> {
+        let stem = self.source.file_stem(self.y, self.d);
+        let prefix = format!("{stem}.example");
+        let entries = match std::fs::read_dir(&self.c.inputs_root) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(CheckerError::example_file(&self.c.inputs_root)(e)),
         };
-        let result = (self.runner)(&mut BufReader::new(input.trim_matches('\n').as_bytes()))
-            .context("Failed to run on example")?;
+        let mut numbers = entries
+            .filter_map(|entry| {
+                let name = entry.ok()?.file_name().into_string().ok()?;
+                let n = name.strip_prefix(&prefix)?.strip_suffix(".in")?;
+                n.parse::<usize>().ok().filter(|&n| n > 0)
+            })
+            .collect::<Vec<_>>();
+        numbers.sort_unstable();
+
+        let mut examples = Vec::new();
+        for n in numbers {
+            let path = |ext| self.c.inputs_root.join(format!("{prefix}{n}.{ext}"));
+            let read = |path: PathBuf| {
+                self.c
+                    .read_file(&path)
+                    .map_err(CheckerError::example_file(path))
+            };
+            let Some(input) = read(path("in"))? else {
+                continue;
+            };
+            let Some(output) = read(path("out"))? else {
+                continue;
+            };
+            let expected = output
+                .lines()
+                .nth(usize::from(self.p).saturating_sub(1))
+                .map(str::trim)
+                .filter(|answer| !answer.is_empty() && *answer != "-");
+            if let Some(expected) = expected {
+                examples.push((n, input, expected.parse().unwrap()));
+            }
+        }
+        Ok(examples)
+    }
+
+    /// Check the part on the example of its day and on the example files, returns the number of
+    /// examples checked
+    pub fn check_examples(&self) -> Result<usize> {
+        let example = (self.p >= 2)
+            .then_some(self.example2)
+            .flatten()
+            .or(self.example);
+        let mut checked = 0;
+        if let Some(example) = example {
+            match &self.example_result {
+                Some(expected) => {
+                    self.check(example.trim_matches('\n'), expected, 0)?;
+                    checked += 1;
+                }
+                None => println!("No example"),
+            }
+        }
+        Ok(checked + self.check_example_files()?)
+    }
+
+    /// Check the part on the example files only, returns the number of examples checked
+    pub fn check_example_files(&self) -> Result<usize> {
+        if self.p == 0 {
+            return Ok(0);
+        }
+        let examples = self.example_files()?;
+        for (index, input, expected) in &examples {
+            self.check(input, expected, *index)?;
+        }
+        Ok(examples.len())
+    }
+
+    fn check(&self, input: &str, expected: &Answer, index: usize) -> Result<()> {
+        let shared = self.shared(input);
+        let result = param::on_example(|| self.solve(input, &shared, Run::Example))
+            .context("Failed to run on example")
+            .map_err(CheckerError::Solution)?;
         if &result != expected {
-            bail!("Incorrect example result\n\tGot     \t{result}\n\tExpected\t{expected}",);
+            return Err(CheckerError::ExampleMismatch {
+                got: result,
+                expected: expected.clone(),
+                index,
+            });
         }
         Ok(())
     }
 
-    fn bench(&self, filename: &Path) -> Duration {
-        let mut reader = BufReader::new(File::open(filename).unwrap());
-        if let Some(d) = (self.benchmark_runner)(&mut reader) {
+    fn bench(&self, input: &str, shared: &SharedInput) -> Duration {
+        if let Some(d) = (self.benchmark_runner)(Input::new(input)) {
             return d;
         }
         let count = 100;
         let start = std::time::Instant::now();
         for _ in 0..count {
-            reader.seek(std::io::SeekFrom::Start(0)).unwrap();
-            (self.runner)(&mut reader).unwrap();
+            self.solve(input, shared, Run::Real).unwrap();
         }
         let delta = start.elapsed();
         delta / count
     }
 
-    pub fn run(&self) -> anyhow::Result<(Answer, OutputType, Duration)> {
-        let y = self.y;
-        let d = self.d;
-        // Check example inputs/outputs
-        let example = (self.p == 2)
-            .then_some(self.example2)
-            .flatten()
-            .or(self.example);
-        if let Some(example) = example {
-            self.check(example)?;
+    /// Fetch the puzzle input from the puzzle source, refusing to cache anything that does not
+    /// look like an input
+    fn fetch_input(&self, now: SystemTime) -> Result<()> {
+        let (y, d) = (self.y, self.d);
+        if let Some(unlock) = self.source.unlock_time(y, d)
+            && let Ok(remaining) = unlock.duration_since(now)
+            && !remaining.is_zero()
+        {
+            return Err(CheckerError::NotUnlocked {
+                year: y,
+                day: d,
+                remaining,
+            });
         }
-        let input_file = self.input_file();
-        if !input_file.is_file() {
-            // fetch input file from adventofcode.com
-            let Some(client) = &self.c.client else {
-                bail!("Missing AOC_TOKEN environment variable, cannot fetch input");
-            };
-            let url = reqwest::Url::parse(&format!("{URL_BASE}/{y}/day/{d}/input")).unwrap();
-            let mut resp = client
-                .get(url)
-                .header(
-                    reqwest::header::USER_AGENT,
-                    "github.com/etwyniel/aoc-framework by etwyniel@gmail.com",
-                )
-                .send()?
-                .error_for_status()?;
-            let mut output = File::create(&input_file)?;
-            std::io::copy(&mut resp, &mut output)?;
+        let body = self.source.fetch_input(y, d)?;
+        let input = validate_input(&body)?;
+        self.write_input(&input)
+    }
+
+    /// Fetch the input if it is not cached yet, retrying politely while the server does not
+    /// consider the puzzle unlocked
+    fn prefetch(&self, clock: &impl Clock) -> Result<()> {
+        if self.input_file().is_file() {
+            return Ok(());
+        }
+        wait::retry(
+            clock,
+            Backoff::new(Duration::from_secs(1), Duration::from_secs(30)),
+            10,
+            || self.fetch_input(clock.now()),
+            is_retryable,
+        )
+    }
+
+    /// Save the puzzle page next to the input, returns the path of the page or `None` if the
+    /// source has none
+    fn fetch_puzzle(&self, clock: &impl Clock) -> Result<Option<PathBuf>> {
+        let page = wait::retry(
+            clock,
+            Backoff::new(Duration::from_secs(1), Duration::from_secs(30)),
+            10,
+            || self.fetch_day_page(),
+            is_retryable,
+        )?;
+        let Some(page) = page else {
+            return Ok(None);
+        };
+        let stem = self.source.file_stem(self.y, self.d);
+        let path = self.inputs_dir.join(format!("{stem}.html"));
+        self.c
+            .write_file(&path, &page)
+            .map_err(CheckerError::file(&path))?;
+        Ok(Some(path))
+    }
+
+    pub fn run(&self) -> Result<PartResult> {
+        // Check example inputs/outputs
+        self.check_examples()?;
+        if !self.input_file().is_file() {
+            self.fetch_input(SystemTime::now())?;
         }
 
         // run part on input file
-        let mut reader = BufReader::new(File::open(&input_file)?);
-        let start = std::time::Instant::now();
-        let res = (self.runner)(&mut reader)?;
-        let mut delta = start.elapsed();
+        let input = self.input()?;
+        let shared = self.shared(&input);
+        let (res, mut delta) = self
+            .solve_timed(&input, &shared)
+            .map_err(CheckerError::Solution)?;
 
         // check answer, run benchmark if correct and fast
         let ty = self.check_answer(&res)?;
-        if ty == OutputType::Correct && delta < Duration::from_millis(1) {
-            delta = self.bench(&input_file)
+        if ty == OutputType::Correct
+            && delta < Duration::from_millis(1)
+            && self.combined_with.is_none()
+        {
+            delta = self.bench(&input, &shared);
+            shared.record_time(self.p, delta);
         }
         Ok((res, ty, delta))
     }
 
+    /// Run the part on a custom input, without touching the cached puzzle input. The result is
+    /// only checked if an expected answer was given for this part.
+    pub fn run_custom(&self, input: &str) -> Result<PartResult> {
+        let (res, delta) = self
+            .solve_timed(input, &self.shared(input))
+            .map_err(CheckerError::Solution)?;
+
+        let ty = match self.c.expected.get(&self.p) {
+            Some(expected) if expected == &res => OutputType::Correct,
+            Some(expected) => OutputType::Incorrect(expected.to_string()),
+            None => OutputType::Unknown,
+        };
+        Ok((res, ty, delta))
+    }
+
+    /// Run the part on the custom input of the checker, if one was given
+    pub fn run_custom_and_display(&self) {
+        let Some(input) = &self.c.custom_input else {
+            return;
+        };
+        if self.is_filtered_out() {
+            return;
+        }
+
+        let id = format!("{} [{input}]", self.id());
+        let res = self
+            .c
+            .custom_text()
+            .and_then(|text| self.run_custom(text.unwrap_or_default()));
+        display_result(&id, res);
+    }
+
+    fn is_filtered_out(&self) -> bool {
+        !self.c.is_selected(self.d, self.p)
+    }
+
     pub fn run_and_display(&self) {
         // check if part is filtered out
-        let flt = self.c.filters[self.d as usize - 1];
-        if flt != 0 && flt != self.p as i8 {
+        if self.is_filtered_out() {
             return;
         }
 
-        let id = format!("{}-12-{:02}.{}", self.y, self.d, self.p);
-        let (res, ty, delta) = match self.run() {
-            Ok(res) => res,
-            Err(err) => {
-                eprintln!("\x1b[1;31mERR\x1b[0m {id} => {err:?}");
-                return;
-            }
+        display_result(&self.id(), self.run());
+    }
+
+    /// Run the part on the input of every account that has one, comparing with that account's
+    /// known answers. Never fetches or prompts, unknown answers are reported as such.
+    pub fn cross_check(&self) -> Vec<(Option<String>, Result<PartResult>)> {
+        self.c
+            .profile_dirs()
+            .into_iter()
+            .map(|(profile, dir)| (profile, self.clone().with_inputs_dir(dir)))
+            .filter(|(_, checker)| checker.input_file().is_file())
+            .map(|(profile, checker)| (profile, checker.run_offline()))
+            .collect()
+    }
+
+    /// Run the part on the cached input and compare the result with the stored correct answer,
+    /// without any request or prompt. `None` if the input or correct answer is not known.
+    pub fn verify(&self) -> Result<Option<Answer>> {
+        let Some(input) = self.read_input()? else {
+            return Ok(None);
         };
-        let mut status = "OK";
-        let mut color = 32;
-        let mut msg = format!("{res:<15}");
-        match ty {
-            OutputType::Correct => (),
-            OutputType::Incorrect(correct) => {
-                status = "ERR";
-                color = 31;
-                msg = format!("invalid result:\n\tGot:      {res}\n\tExpected: {correct}");
-            }
-            OutputType::TooHigh | OutputType::TooLow | OutputType::Invalid => {
-                status = "ERR";
-                color = 31;
-                let extra = if ty == OutputType::TooHigh {
-                    " (too high)"
-                } else if ty == OutputType::TooLow {
-                    " (too low)"
-                } else {
-                    ""
-                };
-                msg = format!("{res:<15}\n\tinvalid result{extra}");
-            }
-            OutputType::Unknown => {
-                status = "UNK";
-                color = 33;
-            }
+        let Some((Some(correct), _)) = self.get_saved_answers()? else {
+            return Ok(None);
+        };
+        let res = self
+            .solve(&input, &self.shared(&input), Run::Real)
+            .map_err(CheckerError::Solution)?;
+        if !correct.matches(&res.to_string()) {
+            return Err(CheckerError::WrongAnswer {
+                got: res,
+                expected: correct.to_string(),
+            });
+        }
+        Ok(Some(res))
+    }
+
+    fn run_offline(&self) -> Result<PartResult> {
+        let input = self.input()?;
+        let (res, delta) = self
+            .solve_timed(&input, &self.shared(&input))
+            .map_err(CheckerError::Solution)?;
+
+        let (correct, incorrect) = self.get_saved_answers()?.unwrap_or_default();
+        let ty = compare_saved(&res, correct, &incorrect).unwrap_or(OutputType::Unknown);
+        Ok((res, ty, delta))
+    }
+
+    pub fn cross_check_and_display(&self) {
+        if self.is_filtered_out() {
+            return;
+        }
+
+        let results = self.cross_check();
+        if results.is_empty() {
+            eprintln!(
+                "\x1b[1;33mWRN\x1b[0m {} no input found for any account",
+                self.id()
+            );
+        }
+        for (profile, res) in results {
+            let id = format!(
+                "{} [{}]",
+                self.id(),
+                profile.as_deref().unwrap_or("default")
+            );
+            display_result(&id, res);
+        }
+    }
+}
+
+/// Extract the correct answers and number of stars from a puzzle page
+pub(crate) fn parse_day_page(page: &str) -> (Vec<String>, u8) {
+    let mut answers = Vec::with_capacity(2);
+    let mut body = page;
+    while let Some(ndx) = body.find("Your puzzle answer was") {
+        body = &body[ndx..];
+        // find end of opening <code> tag
+        let Some(start) = body.find('>') else {
+            break;
+        };
+        body = &body[(start + 1)..];
+        // find start of closing </code> tag
+        let Some(end) = body.find('<') else { break };
+        answers.push(body[..end].trim().to_string());
+        // advance slice
+        body = &body[end..];
+    }
+    let stars = if page.contains("Both parts of this puzzle are complete") {
+        2
+    } else if page.contains("The first half of this puzzle is complete") {
+        1
+    } else {
+        answers.len().min(2) as u8
+    };
+    (answers, stars)
+}
+
+/// Number of stars recorded in an answers file
+fn stars_in_store(contents: &str) -> u8 {
+    (1..=2u8)
+        .filter(|part| {
+            contents.lines().any(|ln| {
+                ln.strip_prefix(&part.to_string())
+                    .is_some_and(|rest| rest.starts_with(['=', '#']) || rest == "*")
+            })
+        })
+        .count() as u8
+}
+
+/// Whether a request may succeed if retried later, e.g. if the puzzle just unlocked
+fn is_retryable(err: &CheckerError) -> bool {
+    match err {
+        CheckerError::NotUnlocked { .. } | CheckerError::Http(_) => true,
+        CheckerError::InvalidInput(reason) => reason.contains("not unlocked"),
+        CheckerError::HttpStatus { status, .. } => {
+            *status == reqwest::StatusCode::NOT_FOUND || status.is_server_error()
+        }
+        _ => false,
+    }
+}
+
+fn print_causes(err: &dyn std::error::Error) {
+    let mut source = err.source();
+    while let Some(cause) = source {
+        eprintln!("because: {cause}");
+        source = cause.source();
+    }
+}
+
+fn display_result(id: &str, res: Result<PartResult>) {
+    let (res, ty, delta) = match res {
+        Ok(res) => res,
+        Err(err) => {
+            eprintln!("\x1b[1;31mERR\x1b[0m {id} => {err}");
+            print_causes(&err);
+            return;
+        }
+    };
+    let mut status = "OK";
+    let mut color = 32;
+    let mut msg = format!("{res:<15}");
+    match ty {
+        OutputType::Correct => (),
+        OutputType::Incorrect(correct) => {
+            status = "ERR";
+            color = 31;
+            msg = format!("invalid result:\n\tGot:      {res}\n\tExpected: {correct}");
+        }
+        OutputType::TooHigh | OutputType::TooLow | OutputType::Invalid => {
+            status = "ERR";
+            color = 31;
+            let extra = if ty == OutputType::TooHigh {
+                " (too high)"
+            } else if ty == OutputType::TooLow {
+                " (too low)"
+            } else {
+                ""
+            };
+            msg = format!("{res:<15}\n\tinvalid result{extra}");
+        }
+        OutputType::Unknown => {
+            status = "UNK";
+            color = 33;
+        }
+    }
+    eprintln!("\x1b[1;{color}m{status:<3}\x1b[0m {id} =( {delta:^5.0?} )=> {msg}",)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::LocalSource;
+    use std::io::BufRead;
+
+    #[test]
+    fn test_validate_input() {
+        assert_eq!(validate_input("1 2\r\n3 4\r\n").unwrap(), "1 2\n3 4\n");
+        assert!(validate_input("").is_err());
+        assert!(
+            validate_input("Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.\n").is_err()
+        );
+        assert!(
+            validate_input(
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+            )
+            .is_err()
+        );
+        assert!(validate_input("<!DOCTYPE html>\n<html lang=\"en-us\">\n<head>").is_err());
+        let euros = "€".repeat(100);
+        assert_eq!(validate_input(&euros).unwrap(), euros);
+    }
+
+    #[test]
+    fn test_parse_day_page() {
+        let page = "<article><p>Your puzzle answer was <code>1234</code>.</p>\n<p>Your puzzle answer was <code>abc</code>.</p><p class=\"day-success\">Both parts of this puzzle are complete! They provide two gold stars: **</p>";
+        assert_eq!(
+            parse_day_page(page),
+            (vec!["1234".to_string(), "abc".to_string()], 2)
+        );
+        let last_day = "<p>Your puzzle answer was <code>2=0=</code>.</p><p class=\"day-success\">Both parts of this puzzle are complete! They provide two gold stars: **</p>";
+        assert_eq!(parse_day_page(last_day), (vec!["2=0=".to_string()], 2));
+        assert_eq!(parse_day_page("<article></article>"), (vec![], 0));
+
+        assert_eq!(stars_in_store("1<12\n1=15\n"), 1);
+        assert_eq!(stars_in_store("1=15\n2*\n"), 2);
+        assert_eq!(stars_in_store("2>100\n"), 0);
+    }
+
+    #[test]
+    fn test_hashed_answers() {
+        let hashed = hash_saved("1<12\n1=15\n2>100\n2=2=0=\n2@1701407700\n");
+        assert!(!hashed.contains("1=15") && !hashed.contains("2=2=0="));
+        assert!(hashed.contains("1<12\n") && hashed.contains("2>100\n"));
+        assert_eq!(stars_in_store(&hashed), 2);
+
+        let (correct, incorrect) = parse_saved(&hashed, 1);
+        assert!(matches!(correct, Some(KnownAnswer::Hashed { .. })));
+        assert_eq!(
+            compare_saved(&Answer::Num(15), correct.clone(), &incorrect),
+            Some(OutputType::Correct)
+        );
+        assert_eq!(
+            compare_saved(&Answer::Num(10), correct.clone(), &incorrect),
+            Some(OutputType::TooLow)
+        );
+        assert!(matches!(
+            compare_saved(&Answer::Num(16), correct, &incorrect),
+            Some(OutputType::Incorrect(_))
+        ));
+        let (correct, _) = parse_saved(&hashed, 2);
+        assert!(correct.unwrap().matches("2=0="));
+    }
+
+    struct Quest1;
+    struct Sum;
+    struct Product;
+    struct Max;
+
+    fn numbers(input: &mut dyn BufRead) -> Vec<u64> {
+        let mut s = String::new();
+        input.read_to_string(&mut s).unwrap();
+        s.split_whitespace().map(|n| n.parse().unwrap()).collect()
+    }
+
+    impl Part for Sum {
+        const N: u8 = 1;
+        const EXAMPLE_RESULT: Option<Answer> = Some(Answer::Num(3));
+        fn run(mut input: impl BufRead) -> anyhow::Result<Answer> {
+            Ok(numbers(&mut input).iter().sum::<u64>().into())
+        }
+    }
+
+    impl Part for Product {
+        const N: u8 = 2;
+        fn run(mut input: impl BufRead) -> anyhow::Result<Answer> {
+            Ok(numbers(&mut input).iter().product::<u64>().into())
+        }
+    }
+
+    impl Part for Max {
+        const N: u8 = 3;
+        fn run(mut input: impl BufRead) -> anyhow::Result<Answer> {
+            Ok(numbers(&mut input).into_iter().max().unwrap().into())
+        }
+    }
+
+    crate::impl_day!(Quest1::{Sum, Product, Max}: 2024[1], "1 2");
+
+    /// Temporary directory of a test, with an inputs directory and local puzzles, removed when
+    /// the test ends even if it fails
+    struct Fixture {
+        dir: PathBuf,
+    }
+
+    impl Fixture {
+        fn new(name: &str) -> Self {
+            let dir = env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+            _ = std::fs::remove_dir_all(&dir);
+            std::fs::create_dir_all(dir.join("inputs")).unwrap();
+            std::fs::create_dir_all(dir.join("puzzles/2024")).unwrap();
+            Fixture { dir }
+        }
+
+        /// Add the input and answers of `day` of 2024 to the local puzzles
+        fn puzzle(self, day: u8, input: &str, answers: &str) -> Self {
+            let puzzles = self.puzzles();
+            std::fs::write(puzzles.join(format!("2024/{day:02}.in")), input).unwrap();
+            std::fs::write(puzzles.join(format!("2024/{day:02}.ans")), answers).unwrap();
+            self
+        }
+
+        fn inputs(&self) -> PathBuf {
+            self.dir.join("inputs")
         }
-        eprintln!("\x1b[1;{color}m{status:<3}\x1b[0m {id} =( {delta:^5.0?} )=> {msg}",)
+
+        fn puzzles(&self) -> PathBuf {
+            self.dir.join("puzzles")
+        }
+
+        /// Checker of the inputs directory reading puzzles of 3 parts from the local puzzles,
+        /// independent of the token, key and settings of the environment
+        fn checker(&self) -> Checker {
+            Checker::offline(self.inputs())
+                .with_source(LocalSource::new("quests", self.puzzles()).parts(3))
+        }
+    }
+
+    impl Drop for Fixture {
+        fn drop(&mut self) {
+            _ = std::fs::remove_dir_all(&self.dir);
+        }
+    }
+
+    #[test]
+    fn test_local_source() {
+        let fixture = Fixture::new("source").puzzle(1, "2 3 4\n", "9\n24\n4\n");
+        let checker = fixture.checker();
+        let part = checker.for_part::<Quest1, Max>();
+        assert_eq!(part.id(), "quests-2024-01.3");
+        let (answer, ty, _) = part.run().unwrap();
+        assert_eq!((answer, ty), (Answer::Num(4), OutputType::Correct));
+        assert!(fixture.inputs().join("quests-2024-1.in").is_file());
+        let saved = std::fs::read_to_string(fixture.inputs().join("quests-2024-1.out")).unwrap();
+        assert_eq!(saved, "1=9\n2=24\n3=4\n");
+        // answers read from the source weren't confirmed by this run, their solve time is unknown
+        assert!(part.solve_time().is_none());
+        let (_, ty, _) = checker.for_part::<Quest1, Product>().run().unwrap();
+        assert_eq!(ty, OutputType::Correct);
+    }
+
+    #[test]
+    fn test_wait_local_source() {
+        let fixture = Fixture::new("wait").puzzle(1, "2 3 4\n", "9\n24\n4\n");
+        let page = fixture.puzzles().join("2024/01.html");
+        std::fs::write(page, "<h2>Quest 1</h2>").unwrap();
+
+        // no token, nothing may be requested from adventofcode.com
+        fixture.checker().wait_with::<Quest1>(&SystemClock);
+        assert_eq!(
+            std::fs::read_to_string(fixture.inputs().join("quests-2024-1.html")).unwrap(),
+            "<h2>Quest 1</h2>"
+        );
+        let saved = std::fs::read_to_string(fixture.inputs().join("quests-2024-1.out")).unwrap();
+        assert!(saved.starts_with("1=9\n2=24\n3=4\n"));
+    }
+
+    #[test]
+    fn test_offline() {
+        let dir = env::temp_dir().join(format!("aoc-offline-{}", std::process::id()));
+        let checker = Checker::offline(dir.join("inputs"));
+        assert!(checker.client().is_none());
+        let part = checker.for_part::<Quest1, Sum>();
+        assert!(part.verify().unwrap().is_none());
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn test_local_stars() {
+        let fixture = Fixture::new("stars");
+        std::fs::write(fixture.inputs().join("2025-12-12.out"), "1=3\n2=4\n").unwrap();
+        // 2025 only has 12 days
+        std::fs::write(fixture.inputs().join("2025-12-13.out"), "1=3\n").unwrap();
+        let checker = fixture.checker();
+        let stars = checker.local_stars();
+        assert_eq!(stars[&2025][11..13], [2, 0]);
+        assert!(checker.calendar().ends_with(" 2*\n"));
+    }
+
+    #[test]
+    fn test_invalid_profile() {
+        let dir = env::temp_dir().join(format!("aoc-profile-{}", std::process::id()));
+        let checker = Checker::in_dir(None, Some("../escape"), dir.join("inputs"), "");
+        assert!(matches!(checker, Err(CheckerError::InvalidProfile(_))));
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn test_cross_check() {
+        let fixture = Fixture::new("cross");
+        let inputs = fixture.inputs();
+        for (profile, input, answers) in [("", "1 2\n", "1=3\n"), ("work", "4 5\n", "1=10\n")] {
+            std::fs::create_dir_all(inputs.join(profile)).unwrap();
+            std::fs::write(inputs.join(profile).join("quests-2024-1.in"), input).unwrap();
+            std::fs::write(inputs.join(profile).join("quests-2024-1.out"), answers).unwrap();
+        }
+        std::fs::create_dir_all(inputs.join("notes")).unwrap();
+        std::fs::write(inputs.join("notes/todo.txt"), "").unwrap();
+
+        let checker = fixture.checker();
+        let profiles = checker
+            .profile_dirs()
+            .into_iter()
+            .map(|(profile, _)| profile);
+        assert_eq!(
+            profiles.collect::<Vec<_>>(),
+            [None, Some("work".to_string())]
+        );
+        let results = checker
+            .for_part::<Quest1, Sum>()
+            .cross_check()
+            .into_iter()
+            .map(|(profile, res)| (profile, res.map(|(answer, ty, _)| (answer, ty)).unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            [
+                (None, (Answer::Num(3), OutputType::Correct)),
+                (
+                    Some("work".to_string()),
+                    (Answer::Num(9), OutputType::Incorrect("10".to_string()))
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_custom_input() {
+        let fixture = Fixture::new("custom");
+        let path = fixture.dir.join("custom.txt");
+        std::fs::write(&path, "2 3\n").unwrap();
+        let expected = parse_expected("1:5, 2:7").unwrap();
+        assert_eq!(expected.get(&2), Some(&Answer::Num(7)));
+        assert!(parse_expected("42").is_err());
+
+        let checker = fixture
+            .checker()
+            .with_input(CustomInput::File(path.clone()))
+            .with_expected(1, Answer::Num(5))
+            .with_expected(2, Answer::Num(7));
+        let text = checker.custom_text().unwrap().unwrap();
+        let (_, ty, _) = checker.for_part::<Quest1, Sum>().run_custom(text).unwrap();
+        assert_eq!(ty, OutputType::Correct);
+        // the input is read once, parts after the first don't see it change
+        std::fs::remove_file(&path).unwrap();
+        let text = checker.custom_text().unwrap().unwrap();
+        let (answer, ty, _) = checker
+            .for_part::<Quest1, Product>()
+            .run_custom(text)
+            .unwrap();
+        assert_eq!(
+            (answer, ty),
+            (Answer::Num(6), OutputType::Incorrect("7".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_stale_leaderboard() {
+        let fixture = Fixture::new("leaderboard");
+        let checker = fixture.checker();
+        let cache = fixture.inputs().join("leaderboard-2023-1.json");
+        std::fs::write(
+            &cache,
+            include_str!("../tests/fixtures/leaderboard_2023.json"),
+        )
+        .unwrap();
+        let stale = SystemTime::now() - 2 * leaderboard::REFRESH_INTERVAL;
+        File::options()
+            .append(true)
+            .open(&cache)
+            .and_then(|file| file.set_modified(stale))
+            .unwrap();
+
+        // no token, the refresh fails and the cached copy is used
+        assert_eq!(checker.leaderboard(2023, 1).unwrap().event, "2023");
+        let modified = std::fs::metadata(&cache).unwrap().modified().unwrap();
+        assert!(modified.elapsed().unwrap() < leaderboard::REFRESH_INTERVAL);
+    }
+
+    #[test]
+    fn test_example_files() {
+        let fixture = Fixture::new("examples");
+        let inputs = fixture.inputs();
+        let checker = fixture.checker();
+        std::fs::write(inputs.join("quests-2024-1.example1.in"), "1 2 3\n").unwrap();
+        std::fs::write(inputs.join("quests-2024-1.example1.out"), "6\n6\n").unwrap();
+        // numbers may have gaps
+        std::fs::write(inputs.join("quests-2024-1.example3.in"), "2 5\n").unwrap();
+        std::fs::write(inputs.join("quests-2024-1.example3.out"), "-\n11\n").unwrap();
+        std::fs::write(inputs.join("quests-2024-11.example2.in"), "1\n").unwrap();
+
+        assert_eq!(
+            checker.for_part::<Quest1, Sum>().check_examples().unwrap(),
+            2
+        );
+        assert_eq!(
+            checker.for_part::<Quest1, Max>().check_examples().unwrap(),
+            0
+        );
+        let err = checker.for_part::<Quest1, Product>().check_examples();
+        assert!(matches!(
+            err,
+            Err(CheckerError::ExampleMismatch { index: 3, .. })
+        ));
+        std::fs::write(inputs.join("quests-2024-1.example3.out"), [0xff]).unwrap();
+        let err = checker.for_part::<Quest1, Sum>().check_examples();
+        assert!(matches!(err, Err(CheckerError::ExampleFile { .. })));
+    }
+
+    #[test]
+    fn test_encrypted_store() {
+        let fixture = Fixture::new("encrypted").puzzle(1, "2 3 4\n", "9\n");
+        let inputs = fixture.inputs();
+
+        // files stored before a key was set are migrated on demand
+        std::fs::write(inputs.join("quests-2024-2.in"), "5\n").unwrap();
+        std::fs::write(inputs.join("quests-2024-2.html"), "<h2>Quest 2</h2>").unwrap();
+        let key = Cipher::generate_key();
+        let encrypted = fixture
+            .checker()
+            .with_cipher(Some(Cipher::new(&key).unwrap()));
+        assert_eq!(encrypted.encrypt_stored_files().unwrap(), 2);
+        assert_eq!(encrypted.encrypt_stored_files().unwrap(), 0);
+        assert!(crypt::is_encrypted(
+            &std::fs::read(inputs.join("quests-2024-2.in")).unwrap()
+        ));
+
+        let (_, ty, _) = encrypted.for_part::<Quest1, Sum>().run().unwrap();
+        assert_eq!(ty, OutputType::Correct);
+        for file in ["quests-2024-1.in", "quests-2024-1.out"] {
+            let data = std::fs::read(inputs.join(file)).unwrap();
+            assert!(crypt::is_encrypted(&data));
+        }
+        // answers are read back from the encrypted store
+        std::fs::remove_file(fixture.puzzles().join("2024/01.ans")).unwrap();
+        let (_, ty, _) = encrypted.for_part::<Quest1, Sum>().run().unwrap();
+        assert_eq!(ty, OutputType::Correct);
+
+        let plain = fixture.checker();
+        assert!(plain.for_part::<Quest1, Sum>().run().is_err());
+    }
+
+    struct Quest2;
+    struct Total;
+    struct Mean;
+
+    thread_local! {
+        static PARSED: std::cell::Cell<u32> = const { std::cell::Cell::new(0) };
+    }
+
+    impl crate::ParsedDay for Quest2 {
+        type Input = Vec<u64>;
+
+        fn parse(input: Input<'_>) -> anyhow::Result<Vec<u64>> {
+            PARSED.set(PARSED.get() + 1);
+            Ok(numbers(&mut input.reader()))
+        }
+    }
+
+    impl Part for Total {
+        const N: u8 = 1;
+        fn run_shared(input: &str, shared: &SharedInput) -> anyhow::Result<Answer> {
+            Ok(shared.get::<Quest2>(input)?.iter().sum::<u64>().into())
+        }
+    }
+
+    impl Part for Mean {
+        const N: u8 = 2;
+        fn run_shared(input: &str, shared: &SharedInput) -> anyhow::Result<Answer> {
+            let Some(Answer::Num(total)) = shared.answer(1) else {
+                anyhow::bail!("part 1 was not run");
+            };
+            Ok((total / shared.get::<Quest2>(input)?.len() as u64).into())
+        }
+    }
+
+    crate::impl_day!(Quest2::{Total, Mean}: 2024[2]);
+
+    #[test]
+    fn test_shared_input() {
+        let fixture = Fixture::new("shared").puzzle(2, "2 3 4\n", "9\n3\n");
+        let checker = fixture.checker();
+
+        assert!(checker.for_part::<Quest2, Mean>().run().is_err());
+        let (_, ty, _) = checker.for_part::<Quest2, Total>().run().unwrap();
+        assert_eq!(ty, OutputType::Correct);
+        let (answer, ty, _) = checker.for_part::<Quest2, Mean>().run().unwrap();
+        assert_eq!((answer, ty), (Answer::Num(3), OutputType::Correct));
+        assert_eq!(PARSED.get(), 1);
+
+        // answers of another day on the same input are not shared
+        let (answer, _, _) = checker
+            .for_part::<Quest1, Sum>()
+            .run_custom("1 5\n")
+            .unwrap();
+        assert_eq!(answer, Answer::Num(6));
+        assert!(
+            checker
+                .for_part::<Quest2, Mean>()
+                .run_custom("1 5\n")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_shared_input_examples() {
+        let fixture = Fixture::new("shared-examples").puzzle(2, "2 3 4\n", "9\n3\n");
+        let examples = [
+            ("1", "1\n1"),
+            ("2 4", "6\n3"),
+            ("3", "3\n3"),
+            ("5 5", "10\n5"),
+        ];
+        for (i, (input, answers)) in examples.into_iter().enumerate() {
+            let name = format!("quests-2024-2.example{}", i + 1);
+            std::fs::write(fixture.inputs().join(format!("{name}.in")), input).unwrap();
+            std::fs::write(fixture.inputs().join(format!("{name}.out")), answers).unwrap();
+        }
+        let checker = fixture.checker();
+
+        // examples don't evict the real input between the parts
+        let (_, ty, _) = checker.for_part::<Quest2, Total>().run().unwrap();
+        assert_eq!(ty, OutputType::Correct);
+        let (_, ty, _) = checker.for_part::<Quest2, Mean>().run().unwrap();
+        assert_eq!(ty, OutputType::Correct);
+        assert_eq!(PARSED.get(), 5);
     }
 }
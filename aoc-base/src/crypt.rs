@@ -0,0 +1,164 @@
+use std::io;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use sha2::{Digest, Sha256};
+
+use crate::error::{CheckerError, Result};
+
+/// Prefix of encrypted files, followed by the nonce and the ciphertext
+const MAGIC: &[u8] = b"AOCENC1\n";
+const NONCE_LEN: usize = 12;
+const KEY_LEN: usize = 32;
+
+/// Authenticated encryption of inputs and answers, so that they can be committed publicly
+pub struct Cipher {
+    aead: ChaCha20Poly1305,
+}
+
+impl Cipher {
+    /// Use a random 32-byte key given in hex or base64. Passphrases are rejected, they are too
+    /// easy to brute force once encrypted files are public.
+    pub fn new(key: &str) -> Result<Self> {
+        let key = key.trim();
+        let bytes = match from_hex(key) {
+            Some(bytes) => bytes,
+            None => BASE64
+                .decode(key)
+                .map_err(|_| CheckerError::InvalidEncryptionKey)?,
+        };
+        if bytes.len() != KEY_LEN {
+            return Err(CheckerError::InvalidEncryptionKey);
+        }
+        Ok(Cipher {
+            aead: ChaCha20Poly1305::new(Key::from_slice(&bytes)),
+        })
+    }
+
+    /// New random key, in the hex format accepted by [`Cipher::new`]
+    pub fn generate_key() -> String {
+        let mut key = [0; KEY_LEN];
+        OsRng.fill_bytes(&mut key);
+        to_hex(&key)
+    }
+
+    pub fn encrypt(&self, plaintext: &[u8]) -> Vec<u8> {
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .aead
+            .encrypt(&nonce, plaintext)
+            .expect("plaintext too large");
+        [MAGIC, nonce.as_slice(), &ciphertext].concat()
+    }
+
+    /// Decrypt data written by [`Cipher::encrypt`], data that is not encrypted is returned as is
+    pub fn decrypt(&self, data: Vec<u8>) -> io::Result<Vec<u8>> {
+        let Some(rest) = data.strip_prefix(MAGIC) else {
+            return Ok(data);
+        };
+        if rest.len() < NONCE_LEN {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "truncated encrypted file",
+            ));
+        }
+        let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+        self.aead
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "failed to decrypt, wrong key or corrupted file",
+                )
+            })
+    }
+}
+
+pub fn is_encrypted(data: &[u8]) -> bool {
+    data.starts_with(MAGIC)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn from_hex(s: &str) -> Option<Vec<u8>> {
+    if !s.len().is_multiple_of(2) || !s.is_ascii() {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok())
+        .collect()
+}
+
+/// Random salt for [`hash_answer`]
+pub fn new_salt() -> String {
+    let mut salt = [0; 8];
+    OsRng.fill_bytes(&mut salt);
+    to_hex(&salt)
+}
+
+/// Salted hash of an answer, so that it can be checked without being revealed
+pub fn hash_answer(salt: &str, answer: &str) -> String {
+    let hash = Sha256::new()
+        .chain_update(salt.as_bytes())
+        .chain_update(b":")
+        .chain_update(answer.trim().as_bytes())
+        .finalize();
+    to_hex(&hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let key = Cipher::generate_key();
+        let cipher = Cipher::new(&key).unwrap();
+        let encrypted = cipher.encrypt(b"1=1234\n");
+        assert!(is_encrypted(&encrypted));
+        assert_ne!(cipher.encrypt(b"1=1234\n"), encrypted);
+        assert_eq!(cipher.decrypt(encrypted.clone()).unwrap(), b"1=1234\n");
+        assert_eq!(cipher.decrypt(b"plain".to_vec()).unwrap(), b"plain");
+
+        let other = Cipher::new(&Cipher::generate_key()).unwrap();
+        assert!(other.decrypt(encrypted.clone()).is_err());
+        let mut tampered = encrypted;
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(cipher.decrypt(tampered).is_err());
+    }
+
+    #[test]
+    fn test_key_formats() {
+        let hex = Cipher::generate_key();
+        assert_eq!(hex.len(), 64);
+        let base64 = BASE64.encode(from_hex(&hex).unwrap());
+        let encrypted = Cipher::new(&hex).unwrap().encrypt(b"1=1234\n");
+        let decrypted = Cipher::new(&format!("{base64}\n"))
+            .unwrap()
+            .decrypt(encrypted);
+        assert_eq!(decrypted.unwrap(), b"1=1234\n");
+
+        for key in ["hunter2", "", &hex[..62], &BASE64.encode([0; 16])] {
+            assert!(matches!(
+                Cipher::new(key),
+                Err(CheckerError::InvalidEncryptionKey)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_hash_answer() {
+        let salt = new_salt();
+        assert_eq!(salt.len(), 16);
+        assert_ne!(new_salt(), salt);
+        assert_eq!(hash_answer(&salt, "1234"), hash_answer(&salt, "1234\n"));
+        assert_ne!(hash_answer(&salt, "1234"), hash_answer(&salt, "1235"));
+        assert_ne!(hash_answer(&salt, "1234"), hash_answer(&new_salt(), "1234"));
+    }
+}
@@ -0,0 +1,64 @@
+//! [`debug!`](crate::debug) output of solutions, shown on examples only unless `AOC_VERBOSE` is
+//! set.
+
+use std::sync::OnceLock;
+
+use crate::checker::env_flag;
+use crate::param::is_example;
+
+/// Whether `AOC_VERBOSE` asks for debug output on real inputs too, read once
+fn verbose() -> bool {
+    static VERBOSE: OnceLock<bool> = OnceLock::new();
+    *VERBOSE.get_or_init(|| env_flag("AOC_VERBOSE"))
+}
+
+/// Whether [`debug!`](crate::debug) prints anything: on examples, or everywhere with
+/// `AOC_VERBOSE`
+#[inline]
+pub fn enabled() -> bool {
+    is_example() || verbose()
+}
+
+/// `eprintln!` while checking examples, or on every input when `AOC_VERBOSE` is set. The arguments
+/// are only evaluated when printed, so that timings on real inputs are barely affected.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::debug::enabled() {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process::Command;
+
+    use crate::param::on_example;
+
+    #[test]
+    fn test_debug_output() {
+        if std::env::var_os("AOC_DEBUG_CHILD").is_some() {
+            crate::debug!("real input {}", 1);
+            on_example(|| crate::debug!("example {}", 2));
+            return;
+        }
+        // the test harness captures eprintln!, run this test again in a child process
+        let run = |verbose: &str| {
+            let output = Command::new(std::env::current_exe().unwrap())
+                .args(["debug::tests::test_debug_output", "--exact", "--nocapture"])
+                .env("AOC_DEBUG_CHILD", "1")
+                .env("AOC_VERBOSE", verbose)
+                .output()
+                .unwrap();
+            assert!(output.status.success());
+            String::from_utf8(output.stderr).unwrap()
+        };
+        let quiet = run("");
+        assert!(quiet.contains("example 2\n"));
+        assert!(!quiet.contains("real input"));
+        let verbose = run("1");
+        assert!(verbose.contains("real input 1\n"));
+        assert!(verbose.contains("example 2\n"));
+    }
+}
@@ -0,0 +1,106 @@
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use thiserror::Error;
+
+use crate::Answer;
+use crate::release::format_countdown;
+
+fn example_name(index: usize) -> String {
+    match index {
+        0 => "example".to_string(),
+        n => format!("example file {n}"),
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum CheckerError {
+    #[error("missing or invalid AOC token, cannot {0}")]
+    MissingToken(&'static str),
+    #[error("day {day} of {year} is not unlocked yet, unlocks in {}", format_countdown(*.remaining))]
+    NotUnlocked {
+        year: u16,
+        day: u8,
+        remaining: Duration,
+    },
+    #[error("request failed")]
+    Http(#[from] reqwest::Error),
+    #[error("request failed with status {status}: {body}")]
+    HttpStatus {
+        status: reqwest::StatusCode,
+        body: String,
+    },
+    #[error("failed to parse response")]
+    Json(#[from] serde_json::Error),
+    #[error("refusing to cache downloaded input: {0}")]
+    InvalidInput(&'static str),
+    #[error("failed to access answers in {}", path.display())]
+    AnswerStore {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("failed to access {}", path.display())]
+    File {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("failed to read example file {}", path.display())]
+    ExampleFile {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+    #[error("invalid profile name {0:?}")]
+    InvalidProfile(String),
+    #[error(
+        "invalid encryption key, expected 32 random bytes in hex or base64, e.g. from `openssl rand -hex 32`"
+    )]
+    InvalidEncryptionKey,
+    #[error("invalid filter {0:?}")]
+    InvalidFilter(String),
+    #[error("invalid expected answer {0:?}, expected one per part as in 1:42,2:17")]
+    InvalidExpected(String),
+    #[error("Incorrect result for {}\n\tGot     \t{got}\n\tExpected\t{expected}", example_name(*.index))]
+    ExampleMismatch {
+        got: Answer,
+        expected: Answer,
+        /// 0 for the example of the day, `N` for `{stem}.exampleN.in`
+        index: usize,
+    },
+    #[error("Incorrect result\n\tGot     \t{got}\n\tExpected\t{expected}")]
+    WrongAnswer { got: Answer, expected: String },
+    #[error("Conflicting answers:\n\t{answer} is too {}, but\n\t{previous} was too {}",
+        if *.too_low { "low" } else { "high" },
+        if *.too_low { "high" } else { "low" })]
+    ConflictingAnswers {
+        answer: u64,
+        too_low: bool,
+        previous: String,
+    },
+    #[error("{0:#}")]
+    Solution(anyhow::Error),
+}
+
+pub type Result<T, E = CheckerError> = std::result::Result<T, E>;
+
+impl CheckerError {
+    pub(crate) fn answer_store(path: impl Into<PathBuf>) -> impl FnOnce(io::Error) -> Self {
+        let path = path.into();
+        move |source| CheckerError::AnswerStore { path, source }
+    }
+
+    pub(crate) fn file(path: impl Into<PathBuf>) -> impl FnOnce(io::Error) -> Self {
+        let path = path.into();
+        move |source| CheckerError::File { path, source }
+    }
+
+    pub(crate) fn example_file(path: impl Into<PathBuf>) -> impl FnOnce(io::Error) -> Self {
+        let path = path.into();
+        move |source| CheckerError::ExampleFile { path, source }
+    }
+}
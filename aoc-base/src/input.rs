@@ -0,0 +1,137 @@
+//! Puzzle input loaded in memory, and its conversions into the argument types supported by
+//! `#[aoc]`. Malformed inputs panic with the offending line, like the solutions themselves would.
+
+use std::fmt::Debug;
+use std::io::{self, BufRead};
+use std::str::FromStr;
+
+/// Input of a part, borrowed from the checker which reads it once, so that running a part again,
+/// e.g. in a benchmark, neither reads nor copies it
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Input<'a> {
+    text: &'a str,
+}
+
+impl<'a> Input<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Input { text }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.text.as_bytes()
+    }
+
+    /// The input as a [`BufRead`], for parts reading it themselves
+    pub fn reader(&self) -> &'a [u8] {
+        self.text.as_bytes()
+    }
+
+    pub fn lines(&self) -> std::str::Lines<'a> {
+        self.text.lines()
+    }
+
+    pub fn byte_lines(&self) -> impl Iterator<Item = &'a [u8]> + use<'a> {
+        self.text.lines().map(str::as_bytes)
+    }
+
+    /// One value per line
+    pub fn parse_lines<T: FromStr>(&self) -> impl Iterator<Item = T> + use<'a, T>
+    where
+        T::Err: Debug,
+    {
+        self.lines()
+            .enumerate()
+            .map(|(n, line)| parse_line(n, line))
+    }
+
+    /// Groups of lines separated by blank lines
+    pub fn sections(&self) -> impl Iterator<Item = Input<'a>> + use<'a> {
+        let mut rest = self.text;
+        std::iter::from_fn(move || {
+            let len = |blank: bool, text: &str| {
+                text.split_inclusive('\n')
+                    .take_while(|line| line.trim().is_empty() == blank)
+                    .map(str::len)
+                    .sum::<usize>()
+            };
+            rest = &rest[len(true, rest)..];
+            if rest.is_empty() {
+                return None;
+            }
+            let (section, tail) = rest.split_at(len(false, rest));
+            rest = tail;
+            Some(Input::new(section))
+        })
+    }
+
+    /// Lines of each section
+    pub fn section_lines(&self) -> impl Iterator<Item = Vec<String>> + use<'a> {
+        self.sections()
+            .map(|section| section.lines().map(str::to_string).collect())
+    }
+}
+
+impl<'a> From<&'a str> for Input<'a> {
+    fn from(text: &'a str) -> Self {
+        Input::new(text)
+    }
+}
+
+/// Read a whole input, for parts given a [`BufRead`] that convert it to an [`Input`]
+pub fn read_string(mut input: impl BufRead) -> io::Result<String> {
+    let mut out = String::new();
+    input.read_to_string(&mut out)?;
+    Ok(out)
+}
+
+fn parse_line<T: FromStr>(n: usize, line: &str) -> T
+where
+    T::Err: Debug,
+{
+    line.trim()
+        .parse()
+        .unwrap_or_else(|e| panic!("failed to parse line {}, {line:?}: {e:?}", n + 1))
+}
+
+/// Next section of the input, for the `n`th element of a tuple of sections
+pub fn take_section(sections: &mut impl Iterator<Item = Vec<String>>, n: usize) -> Vec<String> {
+    sections
+        .next()
+        .unwrap_or_else(|| panic!("input has no section {}", n + 1))
+}
+
+pub fn parse_section<T: FromStr>(section: Vec<String>) -> Vec<T>
+where
+    T::Err: Debug,
+{
+    section
+        .iter()
+        .enumerate()
+        .map(|(n, line)| parse_line(n, line))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sections() {
+        let input = Input::new("\n1\n2\n\n \n3\n\nabc\n");
+        let sections = input.section_lines().collect::<Vec<_>>();
+        assert_eq!(sections.len(), 3);
+        assert_eq!(parse_section::<u32>(sections[0].clone()), [1, 2]);
+        assert_eq!(sections[2], ["abc"]);
+        assert_eq!(input.sections().nth(1).unwrap().as_str(), "3\n");
+        assert_eq!(
+            Input::new("1\n-2 \n")
+                .parse_lines::<i32>()
+                .collect::<Vec<_>>(),
+            [1, -2]
+        );
+    }
+}
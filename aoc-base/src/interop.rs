@@ -0,0 +1,185 @@
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::OutputType;
+use crate::checker::{Checker, KnownAnswer, validate_input};
+use crate::error::{CheckerError, Result};
+
+/// Cache layout of another Advent of Code helper
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Layout {
+    /// advent-of-code-data, e.g. `~/.config/aocd/{user}/2023_01_input.txt` and
+    /// `2023_01a_answer.txt`. The directory is the one of a single user.
+    Aocd,
+    /// cargo-aoc, e.g. `input/2023/day1.txt` in the crate. Only holds inputs.
+    CargoAoc,
+}
+
+impl Layout {
+    fn input_path(self, dir: &Path, year: u16, day: u8) -> PathBuf {
+        match self {
+            Layout::Aocd => dir.join(format!("{year}_{day:02}_input.txt")),
+            Layout::CargoAoc => dir
+                .join("input")
+                .join(year.to_string())
+                .join(format!("day{day}.txt")),
+        }
+    }
+
+    fn answer_path(self, dir: &Path, year: u16, day: u8, part: u8) -> Option<PathBuf> {
+        let part = match part {
+            1 => 'a',
+            2 => 'b',
+            _ => return None,
+        };
+        match self {
+            Layout::Aocd => Some(dir.join(format!("{year}_{day:02}{part}_answer.txt"))),
+            Layout::CargoAoc => None,
+        }
+    }
+}
+
+/// Number of files copied by an import or export
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Transferred {
+    pub inputs: u32,
+    pub answers: u32,
+}
+
+impl Display for Transferred {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} inputs, {} answers", self.inputs, self.answers)
+    }
+}
+
+fn read_if_exists(path: &Path) -> Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(CheckerError::file(path)(e)),
+    }
+}
+
+fn write_new(path: &Path, contents: &str) -> Result<bool> {
+    if path.exists() {
+        return Ok(false);
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(CheckerError::file(parent))?;
+    }
+    fs::write(path, contents).map_err(CheckerError::file(path))?;
+    Ok(true)
+}
+
+impl Checker {
+    /// Fill the inputs directory and answers store with the inputs and correct answers of `year`
+    /// found in `dir`. Inputs and answers that are already known are left untouched.
+    pub fn import(&self, layout: Layout, dir: &Path, year: u16) -> Result<Transferred> {
+        let mut count = Transferred::default();
+        for day in 1..=25 {
+            let checker = self.for_puzzle(year, day, 1);
+            if !checker.input_file().is_file()
+                && let Some(input) = read_if_exists(&layout.input_path(dir, year, day))?
+            {
+                checker.write_input(&validate_input(&input)?)?;
+                count.inputs += 1;
+            }
+            for part in 1..=2 {
+                let Some(path) = layout.answer_path(dir, year, day, part) else {
+                    continue;
+                };
+                let Some(answer) = read_if_exists(&path)? else {
+                    continue;
+                };
+                let answer = answer.trim();
+                let known = self.for_puzzle(year, day, part).get_saved_answers()?;
+                if answer.is_empty() || known.is_some_and(|(correct, _)| correct.is_some()) {
+                    continue;
+                }
+                checker.save_answer_for_part(answer, &OutputType::Correct, part)?;
+                count.answers += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    /// Write the inputs and correct answers of `year` to `dir` in plain text, without overwriting
+    /// any file
+    pub fn export(&self, layout: Layout, dir: &Path, year: u16) -> Result<Transferred> {
+        let mut count = Transferred::default();
+        for day in 1..=25 {
+            let checker = self.for_puzzle(year, day, 1);
+            if let Some(input) = checker.read_input()?
+                && write_new(&layout.input_path(dir, year, day), &input)?
+            {
+                count.inputs += 1;
+            }
+            for part in 1..=2 {
+                let Some(path) = layout.answer_path(dir, year, day, part) else {
+                    continue;
+                };
+                let known = self.for_puzzle(year, day, part).get_saved_answers()?;
+                // hashed answers can't be exported
+                let Some((Some(KnownAnswer::Plain(correct)), _)) = known else {
+                    continue;
+                };
+                if write_new(&path, &correct)? {
+                    count.answers += 1;
+                }
+            }
+        }
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_import_export() {
+        let dir = std::env::temp_dir().join(format!("aoc-interop-{}", std::process::id()));
+        let aocd = dir.join("aocd");
+        fs::create_dir_all(&aocd).unwrap();
+        fs::write(aocd.join("2022_03_input.txt"), "abc\r\ndef\r\n").unwrap();
+        fs::write(aocd.join("2022_03a_answer.txt"), "157\n").unwrap();
+        fs::write(aocd.join("2021_01_input.txt"), "").unwrap();
+
+        // stored files are compared in plain text, whatever key the environment has
+        fs::create_dir_all(dir.join("inputs")).unwrap();
+        let checker = Checker::offline(dir.join("inputs")).with_cipher(None);
+        // empty inputs are rejected
+        assert!(checker.import(Layout::Aocd, &aocd, 2021).is_err());
+        let count = checker.import(Layout::Aocd, &aocd, 2022).unwrap();
+        assert_eq!(
+            count,
+            Transferred {
+                inputs: 1,
+                answers: 1
+            }
+        );
+        let count = checker.import(Layout::Aocd, &aocd, 2022).unwrap();
+        assert_eq!(count, Transferred::default());
+        assert_eq!(
+            fs::read_to_string(dir.join("inputs/2022-12-3.in")).unwrap(),
+            "abc\ndef\n"
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("inputs/2022-12-3.out")).unwrap(),
+            "1=157\n"
+        );
+
+        let cargo_aoc = dir.join("cargo-aoc");
+        let count = checker.export(Layout::CargoAoc, &cargo_aoc, 2022).unwrap();
+        assert_eq!(
+            count,
+            Transferred {
+                inputs: 1,
+                answers: 0
+            }
+        );
+        assert!(cargo_aoc.join("input/2022/day3.txt").is_file());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use serde::Deserialize;
+
+use crate::error::Result;
+
+/// Minimum time between two fetches of a private leaderboard, as requested by adventofcode.com
+pub const REFRESH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(15 * 60);
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    pub owner_id: u64,
+    pub members: HashMap<String, Member>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u64,
+    pub global_score: u64,
+    pub last_star_ts: u64,
+    /// Stars obtained, indexed by day then part
+    pub completion_day_level: HashMap<String, HashMap<String, Star>>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Star {
+    pub get_star_ts: u64,
+    pub star_index: u64,
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Members ordered by local score, ties broken by stars then by who got their last star first
+    pub fn ranked(&self) -> Vec<&Member> {
+        let mut members = self.members.values().collect::<Vec<_>>();
+        members.sort_by_key(|m| {
+            (
+                std::cmp::Reverse(m.local_score),
+                std::cmp::Reverse(m.stars),
+                m.last_star_ts,
+                m.id,
+            )
+        });
+        members
+    }
+
+    /// Last day for which any member got a star
+    pub fn last_day(&self) -> u8 {
+        self.members
+            .values()
+            .flat_map(|m| m.completion_day_level.keys())
+            .filter_map(|day| day.parse().ok())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Render the leaderboard as a table of stars per day, followed by the time each member took
+    /// to go from part 1 to part 2
+    pub fn render(&self) -> String {
+        let members = self.ranked();
+        let days = 1..=self.last_day();
+        let name_width = members
+            .iter()
+            .map(|m| m.display_name().chars().count())
+            .max()
+            .unwrap_or(0)
+            .max(4);
+
+        let mut out = String::new();
+        _ = writeln!(
+            out,
+            "Private leaderboard {} (owner {})",
+            self.event, self.owner_id
+        );
+        _ = write!(out, "\n   #  Score  {:name_width$}  ", "Name");
+        for day in days.clone() {
+            _ = write!(out, "{}", day % 10);
+        }
+        out.push('\n');
+        for (rank, member) in members.iter().enumerate() {
+            _ = write!(
+                out,
+                "{:>4}  {:>5}  {:name_width$}  ",
+                format!("{})", rank + 1),
+                member.local_score,
+                member.display_name()
+            );
+            for day in days.clone() {
+                out.push_str(match member.day_stars(day) {
+                    2 => "\x1b[1;33m*\x1b[0m",
+                    1 => "\x1b[37m*\x1b[0m",
+                    _ => "\x1b[38;5;8m.\x1b[0m",
+                });
+            }
+            out.push('\n');
+        }
+
+        _ = write!(out, "\nPart 2 delta  {:name_width$}", "");
+        for day in days.clone() {
+            _ = write!(out, " {day:>6}");
+        }
+        out.push('\n');
+        for member in &members {
+            _ = write!(out, "              {:name_width$}", member.display_name());
+            for day in days.clone() {
+                let delta = member.part2_delta(day).map(format_delta);
+                _ = write!(out, " {:>6}", delta.as_deref().unwrap_or("-"));
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    /// Unix timestamp at which the star for `part` of `day` was obtained
+    pub fn star_ts(&self, day: u8, part: u8) -> Option<u64> {
+        let star = self
+            .completion_day_level
+            .get(&day.to_string())?
+            .get(&part.to_string())?;
+        Some(star.get_star_ts)
+    }
+
+    pub fn day_stars(&self, day: u8) -> usize {
+        self.completion_day_level
+            .get(&day.to_string())
+            .map(|parts| parts.len())
+            .unwrap_or(0)
+    }
+
+    /// Seconds between the first and second star of `day`
+    pub fn part2_delta(&self, day: u8) -> Option<u64> {
+        Some(self.star_ts(day, 2)?.saturating_sub(self.star_ts(day, 1)?))
+    }
+}
+
+/// Compact duration format, e.g. `45s`, `3m07s` or `2h15m`
+pub fn format_delta(secs: u64) -> String {
+    match secs {
+        s if s < 60 => format!("{s}s"),
+        s if s < 3600 => format!("{}m{:02}s", s / 60, s % 60),
+        s if s < 100 * 3600 => format!("{}h{:02}m", s / 3600, s / 60 % 60),
+        s => format!("{}d", s / 86400),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../tests/fixtures/leaderboard_2023.json");
+
+    #[test]
+    fn test_parse_leaderboard() {
+        let lb = Leaderboard::parse(FIXTURE).unwrap();
+        assert_eq!(lb.event, "2023");
+        assert_eq!(lb.members.len(), 4);
+        let ranked = lb.ranked();
+        assert_eq!(
+            ranked.iter().map(|m| m.id).collect::<Vec<_>>(),
+            [1001, 1002, 1003, 1004]
+        );
+        let alice = ranked[0];
+        assert_eq!(alice.star_ts(1, 2), Some(1701407700));
+        assert_eq!(alice.star_ts(3, 2), None);
+        assert_eq!(alice.part2_delta(1), Some(600));
+        assert_eq!(alice.day_stars(3), 1);
+        assert_eq!(ranked[2].display_name(), "(anonymous user #1003)");
+        assert_eq!(lb.last_day(), 3);
+    }
+
+    #[test]
+    fn test_render_leaderboard() {
+        let lb = Leaderboard::parse(FIXTURE).unwrap();
+        let rendered = lb.render();
+        let lines = rendered.lines().collect::<Vec<_>>();
+        assert!(lines[3].starts_with("  1)     19  alice"));
+        assert!(lines[4].starts_with("  2)     13  bob"));
+        assert!(lines[6].starts_with("  4)      0  carol"));
+        let bob_delta = lines.iter().rev().nth(2).unwrap();
+        assert!(bob_delta.trim_start().starts_with("bob"));
+        assert!(bob_delta.ends_with(" 1h05m 53m20s      -"));
+        assert_eq!(format_delta(45), "45s");
+
+        // the name column is as wide as the longest name in characters, not bytes
+        let mut lb = lb;
+        for member in lb.members.values_mut() {
+            if member.name.as_deref() == Some("carol") {
+                member.name = Some("Zoë Ærø-Ørsted-Åkesson-Østergård".to_string());
+            }
+        }
+        let rendered = lb.render();
+        assert!(rendered.contains("  0  Zoë Ærø-Ørsted-Åkesson-Østergård  \x1b"));
+        assert_eq!(format_delta(187), "3m07s");
+    }
+}
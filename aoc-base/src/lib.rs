@@ -3,14 +3,25 @@ pub use anyhow;
 pub use itertools::Itertools;
 
 pub mod checker;
+pub mod crypt;
+pub mod debug;
+pub mod error;
+pub mod input;
+pub mod interop;
+pub mod leaderboard;
+pub mod param;
+pub mod release;
+pub mod shared;
+pub mod source;
+pub mod stats;
+pub mod testing;
+pub mod token;
+pub mod wait;
 
 use anyhow::{Context, bail};
 
 use std::{
-    borrow::Cow,
-    fmt::Display,
-    io::{BufRead, BufReader},
-    time::Duration,
+    borrow::Cow, convert::Infallible, fmt::Display, io::BufRead, str::FromStr, time::Duration,
 };
 
 #[derive(Eq, Clone, Debug)]
@@ -22,6 +33,10 @@ pub enum Answer {
 pub use Answer::*;
 
 use crate::checker::Checker;
+use crate::error::CheckerError;
+use crate::input::Input;
+use crate::param::Run;
+use crate::shared::SharedInput;
 
 impl PartialEq for Answer {
     fn eq(&self, other: &Self) -> bool {
@@ -59,10 +74,71 @@ impl From<String> for Answer {
     }
 }
 
+impl From<&'static str> for Answer {
+    fn from(value: &'static str) -> Self {
+        Str(Cow::Borrowed(value))
+    }
+}
+
+impl From<char> for Answer {
+    fn from(value: char) -> Self {
+        Str(Cow::Owned(value.to_string()))
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($ty:ty),*) => {
+        $(impl From<$ty> for Answer {
+            fn from(value: $ty) -> Self {
+                Num(value as u64)
+            }
+        })*
+    };
+}
+
+impl_from_unsigned!(u8, u16, u32, usize);
+
+macro_rules! impl_from_signed {
+    ($($ty:ty),*) => {
+        $(impl From<$ty> for Answer {
+            /// Negative numbers are kept as strings
+            fn from(value: $ty) -> Self {
+                u64::try_from(value)
+                    .map(Num)
+                    .unwrap_or_else(|_| Str(Cow::Owned(value.to_string())))
+            }
+        })*
+    };
+}
+
+impl_from_signed!(i8, i16, i32, i64, isize);
+
+impl Answer {
+    /// Answer from any displayable value, numbers are recognized as such
+    pub fn from_display(value: &impl Display) -> Self {
+        let Ok(answer) = value.to_string().parse();
+        answer
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    /// Parse an answer as a number if possible, as a string otherwise
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        Ok(s.parse()
+            .map(Num)
+            .unwrap_or_else(|_| Str(Cow::Owned(s.to_string()))))
+    }
+}
+
 pub trait Day: Sized {
     const YEAR: u16;
     const N: u8;
     const EXAMPLE: Option<&'static str> = None;
+    /// Example for the parts after the first, if different. Days with more than two parts have
+    /// a single example for parts 2 and above, other examples go in example files.
     const PART2_EXAMPLE: Option<&'static str> = None;
 
     type Part1: Part;
@@ -70,47 +146,76 @@ pub trait Day: Sized {
 
     fn run(session_key: Option<&str>) {
         let checker = Checker::new(session_key.map(str::to_owned), "").unwrap();
-        if Self::Part1::N != 0 {
-            checker.run_part::<Self, Self::Part1>();
-        } else {
-            eprintln!(
-                "\x1b[1;33mWRN\x1b[0m Day {} part 1 not implemented",
-                Self::N
-            );
-        }
-        if Self::Part2::N != 0 {
-            checker.run_part::<Self, Self::Part2>();
-        } else {
-            eprintln!(
-                "\x1b[1;33mWRN\x1b[0m Day {} part 2 not implemented",
-                Self::N
-            );
-        }
+        Self::run_parts(&checker);
     }
+
+    /// Run every part of the day, override to run more than two parts
+    fn run_parts(checker: &Checker) {
+        checker
+            .run_part_or_warn::<Self, Self::Part1>(1)
+            .run_part_or_warn::<Self, Self::Part2>(2);
+    }
+}
+
+/// Day whose input is parsed once and shared by its parts, which get it from
+/// [`SharedInput::get`] in [`Part::run_shared`]
+pub trait ParsedDay: Day + 'static {
+    type Input: 'static;
+
+    fn parse(input: Input<'_>) -> anyhow::Result<Self::Input>;
 }
 
 pub trait Part {
     const N: u8;
     const EXAMPLE_RESULT: Option<Answer> = None;
+    /// Earlier part whose run also computes the answer of this one, both are timed as one
+    const COMBINED_WITH: Option<u8> = None;
 
     fn run(_input: impl BufRead) -> anyhow::Result<Answer> {
         bail!("Not implemented")
     }
 
-    fn check(input: &str) -> anyhow::Result<()> {
+    /// Run on the input loaded in memory, by default with [`Part::run`] reading from it
+    fn run_input(input: Input<'_>) -> anyhow::Result<Answer> {
+        Self::run(input.reader())
+    }
+
+    /// Run on an input shared with the other parts of the day, by default with
+    /// [`Part::run_input`]
+    fn run_shared(input: &str, _shared: &SharedInput) -> anyhow::Result<Answer> {
+        Self::run_input(Input::new(input))
+    }
+
+    /// Run on an example or on a real input as told by `run`, which parts with a
+    /// [`Param`](param::Param) use to pick its value. This is what the checker calls, by default
+    /// with [`Part::run_shared`].
+    fn run_with(input: &str, shared: &SharedInput, _run: Run) -> anyhow::Result<Answer> {
+        Self::run_shared(input, shared)
+    }
+
+    fn check(input: &str) -> Result<(), CheckerError> {
         let Some(expected) = Self::EXAMPLE_RESULT else {
             println!("No example");
             return Ok(());
         };
-        let result = Self::run(BufReader::new(input.trim_matches('\n').as_bytes()))
-            .context("Failed to run on example")?;
+        let input = input.trim_matches('\n');
+        let result =
+            param::on_example(|| Self::run_with(input, &SharedInput::default(), Run::Example))
+                .context("Failed to run on example")
+                .map_err(CheckerError::Solution)?;
         if result != expected {
-            bail!("Incorrect example result\n\tGot     \t{result}\n\tExpected\t{expected}",);
+            return Err(CheckerError::ExampleMismatch {
+                got: result,
+                expected,
+                index: 0,
+            });
         }
         Ok(())
     }
 
-    fn bench(_input: impl BufRead) -> Option<Duration> {
+    /// Average time of a run, for parts that time only part of their work. `None` lets the
+    /// checker time [`Part::run_shared`].
+    fn bench(_input: Input<'_>) -> Option<Duration> {
         None
     }
 }
@@ -136,33 +241,86 @@ pub enum OutputType {
     Invalid,
 }
 
+/// Implement [`Day`] for up to three parts. The second example is used by every part after the
+/// first.
 #[macro_export]
 macro_rules! impl_day {
+    // parts must be given in order, `()` standing for a missing part
+    (@check $ident:ident, $part:ty, $n:literal) => {
+        const _: () = assert!(
+            matches!(<$part as $crate::Part>::N, 0 | $n),
+            concat!(
+                "part ",
+                stringify!($n),
+                " of ",
+                stringify!($ident),
+                " has another part number"
+            )
+        );
+    };
+    (#[tests = $tests:ident] $ident:ident $($rest:tt)*) => {
+        $crate::impl_day!($ident $($rest)*);
+        #[cfg(test)]
+        mod $tests {
+            $crate::day_tests!(super::$ident);
+        }
+    };
     ($ident:ident: $year:literal[$day:literal]) => {
-        impl_day!($ident::{(), ()}: $year[$day], None, None);
+        $crate::impl_day!($ident::{(), ()}: $year[$day], None, None);
     };
     ($ident:ident::$part1:ty: $year:literal[$day:literal]) => {
-        impl_day!($ident::{$part1, ()}: $year[$day], None, None);
+        $crate::impl_day!($ident::{$part1, ()}: $year[$day], None, None);
     };
     ($ident:ident::{$part1:ty, $part2:ty}: $year:literal[$day:literal]) => {
-        impl_day!($ident::{$part1, $part2}: $year[$day], None, None);
+        $crate::impl_day!($ident::{$part1, $part2}: $year[$day], None, None);
     };
     ($ident:ident: $year:literal[$day:literal], $example:literal) => {
-        impl_day!($ident::{(), ()}: $year[$day], $example);
+        $crate::impl_day!($ident::{(), ()}: $year[$day], $example);
     };
     ($ident:ident::$part1:ty: $year:literal[$day:literal], $example:literal) => {
-        impl_day!($ident::{$part1, ()}: $year[$day], Some($example), None);
+        $crate::impl_day!($ident::{$part1, ()}: $year[$day], Some($example), None);
     };
     ($ident:ident::$part1:ty: $year:literal[$day:literal], $example:literal, $example2:literal) => {
-        impl_day!($ident::{$part1, ()}: $year[$day], Some($example), Some($example2));
+        $crate::impl_day!($ident::{$part1, ()}: $year[$day], Some($example), Some($example2));
     };
     ($ident:ident::{$part1:ty, $part2:ty}: $year:literal[$day:literal], $example:literal) => {
-        impl_day!($ident::{$part1, $part2}: $year[$day], Some($example), None);
+        $crate::impl_day!($ident::{$part1, $part2}: $year[$day], Some($example), None);
     };
     ($ident:ident::{$part1:ty, $part2:ty}: $year:literal[$day:literal], $example:literal, $example2:literal) => {
-        impl_day!($ident::{$part1, $part2}: $year[$day], Some($example), Some($example2));
+        $crate::impl_day!($ident::{$part1, $part2}: $year[$day], Some($example), Some($example2));
+    };
+    ($ident:ident::{$part1:ty, $part2:ty, $part3:ty}: $year:literal[$day:literal]) => {
+        $crate::impl_day!($ident::{$part1, $part2, $part3}: $year[$day], None, None);
+    };
+    ($ident:ident::{$part1:ty, $part2:ty, $part3:ty}: $year:literal[$day:literal], $example:literal) => {
+        $crate::impl_day!($ident::{$part1, $part2, $part3}: $year[$day], Some($example), None);
+    };
+    ($ident:ident::{$part1:ty, $part2:ty, $part3:ty}: $year:literal[$day:literal], $example:literal, $example2:literal) => {
+        $crate::impl_day!($ident::{$part1, $part2, $part3}: $year[$day], Some($example), Some($example2));
+    };
+    ($ident:ident::{$part1:ty, $part2:ty, $part3:ty}: $year:literal[$day:literal], $example:expr, $example2:expr) => {
+        $crate::impl_day!(@check $ident, $part1, 1);
+        $crate::impl_day!(@check $ident, $part2, 2);
+        $crate::impl_day!(@check $ident, $part3, 3);
+        impl $crate::Day for $ident {
+            const YEAR: u16 = $year;
+            const N: u8 = $day;
+            const EXAMPLE: Option<&'static str> = $example;
+            const PART2_EXAMPLE: Option<&'static str> = $example2;
+            type Part1 = $part1;
+            type Part2 = $part2;
+
+            fn run_parts(checker: &$crate::checker::Checker) {
+                checker
+                    .run_part_or_warn::<Self, $part1>(1)
+                    .run_part_or_warn::<Self, $part2>(2)
+                    .run_part_or_warn::<Self, $part3>(3);
+            }
+        }
     };
     ($ident:ident::{$part1:ty, $part2:ty}: $year:literal[$day:literal], $example:expr, $example2:expr) => {
+        $crate::impl_day!(@check $ident, $part1, 1);
+        $crate::impl_day!(@check $ident, $part2, 2);
         impl $crate::Day for $ident {
             const YEAR: u16 = $year;
             const N: u8 = $day;
@@ -174,13 +332,39 @@ macro_rules! impl_day {
     }
 }
 
+/// `#[test]` functions checking the examples and the known answers of the first two parts of a day
+#[macro_export]
+macro_rules! day_tests {
+    ($day:ty) => {
+        #[test]
+        fn part1_example() {
+            $crate::testing::check_example::<$day, <$day as $crate::Day>::Part1>().unwrap();
+        }
+
+        #[test]
+        fn part2_example() {
+            $crate::testing::check_example::<$day, <$day as $crate::Day>::Part2>().unwrap();
+        }
+
+        #[test]
+        fn part1_answer() {
+            $crate::testing::check_answer::<$day, <$day as $crate::Day>::Part1>().unwrap();
+        }
+
+        #[test]
+        fn part2_answer() {
+            $crate::testing::check_answer::<$day, <$day as $crate::Day>::Part2>().unwrap();
+        }
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     struct Day1;
 
-    impl_day!(Day1::{Part1, Part2}: 2021[1], r"
+    impl_day!(#[tests = day1_tests] Day1::{Part1, Part2}: 2021[1], r"
 199
 200
 208
@@ -229,6 +413,39 @@ mod tests {
         }
     }
 
+    /// Part with a parameter, written by hand
+    struct Scaled;
+
+    impl Part for Scaled {
+        const N: u8 = 1;
+        const EXAMPLE_RESULT: Option<Answer> = Some(Num(14));
+
+        fn run_with(input: &str, _shared: &SharedInput, run: Run) -> anyhow::Result<Answer> {
+            let factor = param::Param {
+                example: 2,
+                real: 10,
+            };
+            Ok(Num(input.trim().parse::<u64>()? * factor.get(run)))
+        }
+    }
+
+    #[test]
+    fn test_param_part() -> anyhow::Result<()> {
+        Scaled::check("7")?;
+        let shared = SharedInput::default();
+        assert_eq!(Scaled::run_with("7", &shared, Run::Real)?, Num(70));
+        Ok(())
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Answer::from(3usize), Num(3));
+        assert_eq!(Answer::from(-3i64), ConstStr("-3"));
+        assert_eq!(Answer::from('x'), ConstStr("x"));
+        assert_eq!(Answer::from_display(&1.5), ConstStr("1.5"));
+        assert_eq!(Answer::from_display(&42u128), Num(42));
+    }
+
     #[test]
     fn test_day1_2021() -> anyhow::Result<()> {
         Part1::check(Day1::EXAMPLE.unwrap())?;
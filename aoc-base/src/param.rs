@@ -0,0 +1,70 @@
+use std::cell::Cell;
+
+thread_local! {
+    static EXAMPLE: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Whether the part being run on this thread is checked against an example, for
+/// [`debug!`](crate::debug) output. Solutions get their parameters from [`Run`] instead.
+#[inline]
+pub fn is_example() -> bool {
+    EXAMPLE.get()
+}
+
+/// Run `f` as an example check, [`is_example`] is true until it returns
+pub(crate) fn on_example<T>(f: impl FnOnce() -> T) -> T {
+    struct Restore(bool);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            EXAMPLE.set(self.0);
+        }
+    }
+
+    let _restore = Restore(EXAMPLE.replace(true));
+    f()
+}
+
+/// Kind of input a part is run on, passed by the checker to [`Part::run_with`](crate::Part)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Run {
+    Example,
+    #[default]
+    Real,
+}
+
+/// Parameter of a solution that differs between the examples and the real input, e.g. the size
+/// of a grid or a number of steps
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Param<T> {
+    pub example: T,
+    pub real: T,
+}
+
+impl<T: Clone> Param<T> {
+    /// Value for a run on an input of kind `run`
+    pub fn get(&self, run: Run) -> T {
+        match run {
+            Run::Example => self.example.clone(),
+            Run::Real => self.real.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_param() {
+        let size = Param {
+            example: 7,
+            real: 71,
+        };
+        assert_eq!(size.get(Run::Real), 71);
+        assert_eq!(size.get(Run::Example), 7);
+        assert!(!is_example());
+        assert!(on_example(is_example));
+        assert!(!is_example());
+    }
+}
@@ -0,0 +1,72 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Offset of the puzzle release timezone (UTC-5) from UTC, in seconds
+const RELEASE_UTC_OFFSET: i64 = -5 * 3600;
+
+/// Number of days since 1970-01-01 for a date of the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Time at which the puzzle for `day` of December `year` unlocks, midnight UTC-5
+pub fn unlock_time(year: u16, day: u8) -> SystemTime {
+    let midnight = days_from_civil(year as i64, 12, day as i64) * 86400 - RELEASE_UTC_OFFSET;
+    UNIX_EPOCH + Duration::from_secs(midnight as u64)
+}
+
+/// Number of puzzles of an event, 12 since 2025
+pub fn day_count(year: u16) -> u8 {
+    if year >= 2025 { 12 } else { 25 }
+}
+
+/// Time remaining before the puzzle unlocks at `now`, `None` if it is already unlocked
+pub fn time_until_unlock(year: u16, day: u8, now: SystemTime) -> Option<Duration> {
+    unlock_time(year, day)
+        .duration_since(now)
+        .ok()
+        .filter(|d| !d.is_zero())
+}
+
+/// Format a duration as `[{d}d ]hh:mm:ss`
+pub fn format_countdown(d: Duration) -> String {
+    let secs = d.as_secs();
+    let (days, hours, minutes, seconds) =
+        (secs / 86400, secs / 3600 % 24, secs / 60 % 60, secs % 60);
+    if days > 0 {
+        format!("{days}d {hours:02}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{hours:02}:{minutes:02}:{seconds:02}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlock_time() {
+        // 2023-12-01T05:00:00Z
+        assert_eq!(
+            unlock_time(2023, 1),
+            UNIX_EPOCH + Duration::from_secs(1701406800)
+        );
+        // 2024-12-25T05:00:00Z
+        assert_eq!(
+            unlock_time(2024, 25),
+            UNIX_EPOCH + Duration::from_secs(1735102800)
+        );
+        let before = UNIX_EPOCH + Duration::from_secs(1701406800 - 61);
+        assert_eq!(
+            time_until_unlock(2023, 1, before),
+            Some(Duration::from_secs(61))
+        );
+        assert_eq!(time_until_unlock(2023, 1, unlock_time(2023, 1)), None);
+        assert_eq!(format_countdown(Duration::from_secs(61)), "00:01:01");
+    }
+}
@@ -0,0 +1,73 @@
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::rc::Rc;
+use std::time::Duration;
+
+use crate::input::Input;
+use crate::{Answer, ParsedDay};
+
+/// Input of one day parsed by [`ParsedDay::parse`] and answers of the parts already run on it, so
+/// that the parts of a day run by the same checker parse their input only once
+#[derive(Default)]
+pub struct SharedInput {
+    parsed: RefCell<HashMap<TypeId, Rc<dyn Any>>>,
+    answers: RefCell<HashMap<u8, Answer>>,
+    times: RefCell<HashMap<u8, Duration>>,
+}
+
+impl SharedInput {
+    /// Input of day `D`, parsed on first use. `input` is the input this was created for.
+    pub fn get<D: ParsedDay>(&self, input: &str) -> anyhow::Result<Rc<D::Input>> {
+        let id = TypeId::of::<D>();
+        if let Some(parsed) = self.parsed.borrow().get(&id) {
+            return Ok(parsed
+                .clone()
+                .downcast()
+                .expect("parsed input has the type of its day"));
+        }
+        let parsed = Rc::new(D::parse(Input::new(input))?);
+        self.parsed.borrow_mut().insert(id, parsed.clone());
+        Ok(parsed)
+    }
+
+    /// Answer of `part` on the same input, if it was already run
+    pub fn answer(&self, part: u8) -> Option<Answer> {
+        self.answers.borrow().get(&part).cloned()
+    }
+
+    /// Record the answer of `part`, for parts computed by another one
+    pub fn record_answer(&self, part: u8, answer: &Answer) {
+        self.answers.borrow_mut().insert(part, answer.clone());
+    }
+
+    pub(crate) fn time(&self, part: u8) -> Option<Duration> {
+        self.times.borrow().get(&part).copied()
+    }
+
+    pub(crate) fn record_time(&self, part: u8, time: Duration) {
+        self.times.borrow_mut().insert(part, time);
+    }
+}
+
+/// Shared inputs of the parts run by a checker, by day and input. Only the inputs of the last day
+/// run are kept, whether examples or real inputs, since days run one after the other.
+#[derive(Default)]
+pub(crate) struct SharedInputs {
+    entries: RefCell<HashMap<(u16, u8, u64), Rc<SharedInput>>>,
+}
+
+impl SharedInputs {
+    /// Shared input of `input` for day `day` of `year`, hashing the input once per call
+    pub(crate) fn get(&self, year: u16, day: u8, input: &str) -> Rc<SharedInput> {
+        let mut hasher = DefaultHasher::new();
+        input.hash(&mut hasher);
+        let key = (year, day, hasher.finish());
+        let mut entries = self.entries.borrow_mut();
+        if !entries.contains_key(&key) {
+            entries.retain(|&(y, d, _), _| (y, d) == (year, day));
+        }
+        entries.entry(key).or_default().clone()
+    }
+}
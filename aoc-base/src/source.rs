@@ -0,0 +1,240 @@
+use std::cell::OnceCell;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use reqwest::blocking::Client;
+use reqwest::header::HeaderMap;
+
+use crate::checker::{URL_BASE, parse_day_page};
+use crate::error::{CheckerError, Result};
+use crate::release;
+use crate::token::{self, TokenSource};
+
+/// Where puzzles come from: how they are named, when they unlock, and how to get their inputs and
+/// known answers
+pub trait PuzzleSource {
+    /// Name of the event or site, used in messages
+    fn name(&self) -> &str;
+
+    /// Identifier of a puzzle in the output, e.g. `2023-12-01`
+    fn puzzle_id(&self, year: u16, day: u8) -> String;
+
+    /// Name of the cached files of a puzzle without extension, e.g. `2023-12-1`
+    fn file_stem(&self, year: u16, day: u8) -> String;
+
+    /// Number of parts of each puzzle
+    fn parts(&self) -> u8 {
+        2
+    }
+
+    /// Time at which a puzzle becomes available, `None` if it always is
+    fn unlock_time(&self, _year: u16, _day: u8) -> Option<SystemTime> {
+        None
+    }
+
+    /// Download the input of a puzzle, the checker validates and caches it
+    fn fetch_input(&self, year: u16, day: u8) -> Result<String>;
+
+    /// Page describing a puzzle, saved next to its input when it unlocks. `None` if there is no
+    /// page or it can't be fetched.
+    fn puzzle_page(&self, _year: u16, _day: u8) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    /// Correct answers already given for a puzzle, in order of parts, and the number of stars
+    /// obtained. `None` if they can't be known.
+    fn fetch_answers(&self, _year: u16, _day: u8) -> Result<Option<(Vec<String>, u8)>> {
+        Ok(None)
+    }
+}
+
+fn get_client(session_key: &str) -> Result<Client> {
+    let jar = reqwest::cookie::Jar::default();
+    jar.add_cookie_str(
+        &format!("session={session_key}"),
+        &URL_BASE.parse().unwrap(),
+    );
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        reqwest::header::USER_AGENT,
+        "github.com/etwyniel/aoc-framework by etwyniel@gmail.com"
+            .parse()
+            .unwrap(),
+    );
+    let client = Client::builder()
+        .cookie_provider(Arc::new(jar))
+        .default_headers(headers)
+        .build()?;
+    Ok(client)
+}
+
+/// adventofcode.com, with the account of the session token if one was found
+pub struct AdventOfCode {
+    client: Option<Client>,
+    token_source: Option<TokenSource>,
+    token_valid: OnceCell<bool>,
+}
+
+impl AdventOfCode {
+    pub fn new(token: Option<(String, TokenSource)>) -> Result<Self> {
+        let (client, token_source) = match token {
+            Some((session_key, source)) => (Some(get_client(&session_key)?), Some(source)),
+            None => (None, None),
+        };
+        Ok(AdventOfCode {
+            client,
+            token_source,
+            token_valid: OnceCell::new(),
+        })
+    }
+
+    /// HTTP client, if a token was found and is still valid. The token is checked the first time
+    /// the client is needed, so that runs with cached inputs and answers stay offline.
+    pub fn client(&self) -> Option<&Client> {
+        let client = self.client.as_ref()?;
+        let valid = *self.token_valid.get_or_init(|| {
+            let source = self.token_source.as_ref().unwrap_or(&TokenSource::Argument);
+            match token::validate_token(client) {
+                Ok(true) => true,
+                Ok(false) => {
+                    eprintln!(
+                        "\x1b[1;31mERR\x1b[0m AOC token from {source} is invalid or expired, log in to adventofcode.com and update the session cookie"
+                    );
+                    false
+                }
+                Err(e) => {
+                    // network issues should not disable the client, the actual request will report them
+                    eprintln!("\x1b[1;33mWRN\x1b[0m Could not validate AOC token from {source}: {e}");
+                    true
+                }
+            }
+        });
+        valid.then_some(client)
+    }
+
+    /// GET a page of the site, `None` if there is no valid token
+    pub fn fetch(&self, path: &str) -> Result<Option<String>> {
+        let Some(client) = self.client() else {
+            return Ok(None);
+        };
+        let resp = client.get(format!("{URL_BASE}{path}")).send()?;
+        let status = resp.status();
+        let body = resp.text()?;
+        if !status.is_success() {
+            return Err(CheckerError::HttpStatus {
+                status,
+                body: body.trim().to_string(),
+            });
+        }
+        Ok(Some(body))
+    }
+}
+
+impl PuzzleSource for AdventOfCode {
+    fn name(&self) -> &str {
+        "adventofcode.com"
+    }
+
+    fn puzzle_id(&self, year: u16, day: u8) -> String {
+        format!("{year}-12-{day:02}")
+    }
+
+    fn file_stem(&self, year: u16, day: u8) -> String {
+        format!("{year}-12-{day}")
+    }
+
+    fn unlock_time(&self, year: u16, day: u8) -> Option<SystemTime> {
+        Some(release::unlock_time(year, day))
+    }
+
+    fn fetch_input(&self, year: u16, day: u8) -> Result<String> {
+        self.fetch(&format!("/{year}/day/{day}/input"))?
+            .ok_or(CheckerError::MissingToken("fetch input"))
+    }
+
+    fn puzzle_page(&self, year: u16, day: u8) -> Result<Option<String>> {
+        self.fetch(&format!("/{year}/day/{day}"))
+    }
+
+    fn fetch_answers(&self, year: u16, day: u8) -> Result<Option<(Vec<String>, u8)>> {
+        let page = self.puzzle_page(year, day)?;
+        Ok(page.as_deref().map(parse_day_page))
+    }
+}
+
+/// Puzzles stored in a local directory, as `{year}/{day:02}.in` with the known answers one per
+/// line in `{year}/{day:02}.ans` and the optional description in `{year}/{day:02}.html`. Useful
+/// for private puzzle sets and to test solutions offline.
+pub struct LocalSource {
+    name: String,
+    dir: PathBuf,
+    parts: u8,
+}
+
+impl LocalSource {
+    pub fn new(name: impl Into<String>, dir: impl Into<PathBuf>) -> Self {
+        LocalSource {
+            name: name.into(),
+            dir: dir.into(),
+            parts: 2,
+        }
+    }
+
+    pub fn parts(mut self, parts: u8) -> Self {
+        self.parts = parts;
+        self
+    }
+
+    fn puzzle_file(&self, year: u16, day: u8, ext: &str) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("{day:02}.{ext}"))
+    }
+}
+
+impl PuzzleSource for LocalSource {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn puzzle_id(&self, year: u16, day: u8) -> String {
+        format!("{}-{year}-{day:02}", self.name)
+    }
+
+    fn file_stem(&self, year: u16, day: u8) -> String {
+        format!("{}-{year}-{day}", self.name)
+    }
+
+    fn parts(&self) -> u8 {
+        self.parts
+    }
+
+    fn fetch_input(&self, year: u16, day: u8) -> Result<String> {
+        let path = self.puzzle_file(year, day, "in");
+        std::fs::read_to_string(&path).map_err(CheckerError::file(path))
+    }
+
+    fn puzzle_page(&self, year: u16, day: u8) -> Result<Option<String>> {
+        let path = self.puzzle_file(year, day, "html");
+        match std::fs::read_to_string(&path) {
+            Ok(page) => Ok(Some(page)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(CheckerError::file(path)(e)),
+        }
+    }
+
+    fn fetch_answers(&self, year: u16, day: u8) -> Result<Option<(Vec<String>, u8)>> {
+        let Ok(answers) = std::fs::read_to_string(self.puzzle_file(year, day, "ans")) else {
+            return Ok(None);
+        };
+        let answers = answers
+            .lines()
+            .map(str::trim)
+            .filter(|ln| !ln.is_empty())
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        let stars = answers.len() as u8;
+        Ok(Some((answers, stars)))
+    }
+}
@@ -0,0 +1,181 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::leaderboard::format_delta;
+
+/// Time and global rank of one part, as shown on the personal stats page
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PartStats {
+    /// Time from release to solve, `None` if it took more than a day and the site doesn't say
+    pub time: Option<Duration>,
+    pub rank: Option<u32>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DayStats {
+    pub day: u8,
+    pub parts: [Option<PartStats>; 2],
+}
+
+fn parse_time(s: &str) -> Option<Duration> {
+    let mut secs = 0;
+    for comp in s.split(':') {
+        secs = secs * 60 + comp.parse::<u64>().ok()?;
+    }
+    Some(Duration::from_secs(secs))
+}
+
+fn parse_part(time: &str, rank: &str) -> Option<PartStats> {
+    if time == "-" {
+        return None;
+    }
+    Some(PartStats {
+        time: parse_time(time),
+        rank: rank.parse().ok(),
+    })
+}
+
+/// Parse the table of the personal stats page (`/{year}/leaderboard/self`)
+pub fn parse_self_page(page: &str) -> Vec<DayStats> {
+    let mut stats = page
+        .lines()
+        .filter_map(|ln| {
+            let cols = ln.split_whitespace().collect::<Vec<_>>();
+            let [day, time1, rank1, _, rest @ ..] = cols.as_slice() else {
+                return None;
+            };
+            let day = day.parse::<u8>().ok()?;
+            let part2 = match rest {
+                [time2, rank2, ..] => parse_part(time2, rank2),
+                _ => None,
+            };
+            Some(DayStats {
+                day,
+                parts: [parse_part(time1, rank1), part2],
+            })
+        })
+        .collect::<Vec<_>>();
+    stats.sort_by_key(|day| day.day);
+    stats
+}
+
+/// Merge times recorded locally into the stats from the site, local times are only used when
+/// the site doesn't have a more precise one
+pub fn merge_local(stats: &mut Vec<DayStats>, day: u8, part: u8, time: Duration) {
+    let idx = match stats.binary_search_by_key(&day, |s| s.day) {
+        Ok(idx) => idx,
+        Err(idx) => {
+            stats.insert(
+                idx,
+                DayStats {
+                    day,
+                    ..Default::default()
+                },
+            );
+            idx
+        }
+    };
+    let part = stats[idx].parts[part as usize - 1].get_or_insert_with(Default::default);
+    if part.time.is_none() {
+        part.time = Some(time);
+    }
+}
+
+fn format_time(part: &Option<PartStats>) -> (String, String) {
+    let Some(part) = part else {
+        return ("-".into(), "-".into());
+    };
+    let time = match part.time {
+        Some(t) => {
+            let secs = t.as_secs();
+            format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+        }
+        None => ">24h".into(),
+    };
+    let rank = part.rank.map(|r| r.to_string()).unwrap_or("-".into());
+    (time, rank)
+}
+
+/// Render time to solve and rank of each part, and the time between the two parts
+pub fn render(year: u16, stats: &[DayStats]) -> String {
+    let mut out = String::new();
+    _ = writeln!(out, "{year}");
+    _ = writeln!(out, "Day     Part 1    Rank     Part 2    Rank   Delta");
+    for day in stats {
+        let (time1, rank1) = format_time(&day.parts[0]);
+        let (time2, rank2) = format_time(&day.parts[1]);
+        let delta = match &day.parts {
+            [
+                Some(PartStats { time: Some(t1), .. }),
+                Some(PartStats { time: Some(t2), .. }),
+            ] => format_delta(t2.saturating_sub(*t1).as_secs()),
+            _ => "-".into(),
+        };
+        _ = writeln!(
+            out,
+            "{:>3}  {time1:>9}  {rank1:>6}  {time2:>9}  {rank2:>6}  {delta:>6}",
+            day.day
+        );
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SELF_PAGE: &str = r#"<article><p>These are your personal leaderboard statistics.</p>
+<pre>      <span class="leaderboard-daydesc-first">--------Part 1---------</span>   <span class="leaderboard-daydesc-both">--------Part 2---------</span>
+Day <span class="leaderboard-daydesc-first">      Time    Rank  Score</span>   <span class="leaderboard-daydesc-both">      Time    Rank  Score</span>
+  3   00:12:40    1337      0          -       -      -
+  2   01:05:09    8000      0   01:20:00    7000      0
+  1       &gt;24h   98765      0       &gt;24h   90000      0
+</pre>
+</article>"#;
+
+    #[test]
+    fn test_parse_self_page() {
+        let mut stats = parse_self_page(SELF_PAGE);
+        assert_eq!(stats.len(), 3);
+        assert_eq!(stats[0].day, 1);
+        assert_eq!(
+            stats[0].parts[0],
+            Some(PartStats {
+                time: None,
+                rank: Some(98765)
+            })
+        );
+        assert_eq!(
+            stats[1].parts[1],
+            Some(PartStats {
+                time: Some(Duration::from_secs(4800)),
+                rank: Some(7000)
+            })
+        );
+        assert_eq!(stats[2].parts[1], None);
+
+        merge_local(&mut stats, 1, 1, Duration::from_secs(90000));
+        merge_local(&mut stats, 2, 1, Duration::from_secs(1));
+        merge_local(&mut stats, 4, 1, Duration::from_secs(300));
+        assert_eq!(
+            stats[0].parts[0].as_ref().unwrap().time,
+            Some(Duration::from_secs(90000))
+        );
+        assert_eq!(
+            stats[1].parts[0].as_ref().unwrap().time,
+            Some(Duration::from_secs(3909))
+        );
+        assert_eq!(stats[3].day, 4);
+
+        let rendered = render(2023, &stats);
+        let lines = rendered.lines().collect::<Vec<_>>();
+        assert_eq!(
+            lines[3],
+            "  2    1:05:09    8000    1:20:00    7000  14m51s"
+        );
+        assert_eq!(
+            lines[5],
+            "  4    0:05:00       -          -       -       -"
+        );
+    }
+}
@@ -0,0 +1,67 @@
+//! Checks run by the `#[test]` functions generated by `impl_day!` and `#[aoc]`
+
+use std::io::Write;
+
+use crate::checker::{Checker, default_inputs_root};
+use crate::crypt::Cipher;
+use crate::error::Result;
+use crate::token;
+use crate::{Day, Part};
+
+/// Checker of the cached inputs and answers, which never asks for a token nor downloads anything.
+/// Only the encryption key is looked up, encrypted stores can't be read otherwise.
+fn checker() -> Result<Checker> {
+    let cipher = token::find_encryption_key()
+        .map(|key| Cipher::new(&key))
+        .transpose()?;
+    Ok(Checker::offline(default_inputs_root()).with_cipher(cipher))
+}
+
+/// Report a skipped check. Written to stderr directly rather than with `eprintln!`, which the test
+/// harness captures, so that skips show in the output of passing tests.
+fn skip(reason: &str, day: u8, part: u8) {
+    _ = writeln!(
+        std::io::stderr(),
+        "\x1b[1;33mWRN\x1b[0m skipped: {reason} for day {day} part {part}"
+    );
+}
+
+/// Run a part on the example of its day and on the example files next to the inputs, comparing
+/// with their expected results when known
+pub fn check_example<D: Day, P: Part>() -> Result<()> {
+    let example = match P::N {
+        0 | 1 => D::EXAMPLE,
+        _ => D::PART2_EXAMPLE.or(D::EXAMPLE),
+    };
+    let mut checked = false;
+    if let Some(example) = example
+        && P::N != 0
+        && P::EXAMPLE_RESULT.is_some()
+    {
+        P::check(example)?;
+        checked = true;
+    }
+    if P::N != 0 && default_inputs_root().is_dir() {
+        checked |= checker()?.for_part::<D, P>().check_example_files()? > 0;
+    }
+    if !checked && P::N != 0 {
+        skip("no example", D::N, P::N);
+    }
+    Ok(())
+}
+
+/// Run a part on the cached input and compare with the stored correct answer, if both are known.
+/// Never downloads anything, so that tests stay offline.
+pub fn check_answer<D: Day, P: Part>() -> Result<()> {
+    if P::N == 0 {
+        return Ok(());
+    }
+    if !default_inputs_root().is_dir() {
+        skip("no inputs", D::N, P::N);
+        return Ok(());
+    }
+    if checker()?.for_part::<D, P>().verify()?.is_none() {
+        skip("no known answer", D::N, P::N);
+    }
+    Ok(())
+}
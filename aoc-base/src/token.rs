@@ -0,0 +1,163 @@
+use std::env;
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use reqwest::blocking::Client;
+
+use crate::checker::URL_BASE;
+
+/// Where a session token was found, used to point the user at the right place when it is invalid
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TokenSource {
+    Argument,
+    Env(String),
+    DotEnv(PathBuf),
+    File(PathBuf),
+}
+
+impl Display for TokenSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TokenSource::Argument => write!(f, "argument"),
+            TokenSource::Env(var) => write!(f, "environment variable {var}"),
+            TokenSource::DotEnv(path) => write!(f, "{}", path.display()),
+            TokenSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Name of the variable holding the token for `profile`, e.g. `AOC_TOKEN_WORK` for profile "work"
+pub(crate) fn var_name(profile: Option<&str>) -> String {
+    match profile {
+        None => "AOC_TOKEN".to_string(),
+        Some(profile) => format!(
+            "AOC_TOKEN_{}",
+            profile.to_uppercase().replace(['-', '.', ' '], "_")
+        ),
+    }
+}
+
+/// Whether `profile` can name a directory of the inputs and a token file, without escaping them
+pub fn is_valid_profile(profile: &str) -> bool {
+    !profile.trim().is_empty() && !profile.contains(['/', '\\']) && !profile.contains("..")
+}
+
+fn config_dir() -> Option<PathBuf> {
+    if let Some(dir) = env::var_os("XDG_CONFIG_HOME").filter(|dir| !dir.is_empty()) {
+        return Some(PathBuf::from(dir));
+    }
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(".config"))
+}
+
+/// Path of the token file for `profile`, `~/.config/aocd/token` for the default profile and
+/// `~/.config/aocd/tokens/{profile}` otherwise
+pub fn token_file(profile: Option<&str>) -> Option<PathBuf> {
+    let dir = config_dir()?.join("aocd");
+    Some(match profile {
+        None => dir.join("token"),
+        Some(profile) if is_valid_profile(profile) => dir.join("tokens").join(profile),
+        Some(_) => return None,
+    })
+}
+
+/// Look for `var` in a `.env` file
+fn read_dotenv(path: &Path, var: &str) -> Option<String> {
+    let contents = fs::read_to_string(path).ok()?;
+    contents.lines().find_map(|ln| {
+        let ln = ln.trim();
+        let ln = ln.strip_prefix("export ").unwrap_or(ln);
+        let (key, value) = ln.split_once('=')?;
+        if key.trim() != var {
+            return None;
+        }
+        let value = value.trim().trim_matches(|c| c == '"' || c == '\'');
+        (!value.is_empty()).then(|| value.to_string())
+    })
+}
+
+fn dotenv_paths() -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+    if let Ok(dir) = env::var("CARGO_MANIFEST_DIR") {
+        paths.push(PathBuf::from(dir).join(".env"));
+    }
+    if let Ok(dir) = env::current_dir() {
+        let path = dir.join(".env");
+        if !paths.contains(&path) {
+            paths.push(path);
+        }
+    }
+    paths
+}
+
+/// Look for `var` in the environment, then in `.env` files, then in the file at `path`
+fn find_secret(var: String, path: Option<PathBuf>) -> Option<(String, TokenSource)> {
+    if let Ok(value) = env::var(&var)
+        && !value.trim().is_empty()
+    {
+        return Some((value.trim().to_string(), TokenSource::Env(var)));
+    }
+    for path in dotenv_paths() {
+        if let Some(value) = read_dotenv(&path, &var) {
+            return Some((value, TokenSource::DotEnv(path)));
+        }
+    }
+    let path = path?;
+    let value = fs::read_to_string(&path).ok()?;
+    let value = value.trim();
+    (!value.is_empty()).then(|| (value.to_string(), TokenSource::File(path)))
+}
+
+/// Find the session token for `profile`, looking in order at the `AOC_TOKEN` (or
+/// `AOC_TOKEN_{PROFILE}`) environment variable, `.env` files and the token file
+pub fn find_token(profile: Option<&str>) -> Option<(String, TokenSource)> {
+    find_secret(var_name(profile), token_file(profile))
+}
+
+/// Find the key used to encrypt inputs and answers, in the `AOC_ENCRYPTION_KEY` environment
+/// variable, `.env` files or `~/.config/aocd/encryption_key`
+pub fn find_encryption_key() -> Option<String> {
+    let path = config_dir().map(|dir| dir.join("aocd").join("encryption_key"));
+    find_secret("AOC_ENCRYPTION_KEY".to_string(), path).map(|(key, _)| key)
+}
+
+/// Probe a page that requires being logged in, returns false if the token was rejected
+pub fn validate_token(client: &Client) -> reqwest::Result<bool> {
+    // logged out users get redirected away from the settings page
+    let resp = client.get(format!("{URL_BASE}/settings")).send()?;
+    Ok(resp.status().is_success() && resp.url().path() == "/settings")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dotenv() {
+        let path = env::temp_dir().join(format!("aoc-dotenv-{}", std::process::id()));
+        fs::write(
+            &path,
+            "# comment\nOTHER=1\nexport AOC_TOKEN=\"abc123\"\nAOC_TOKEN_WORK = def456\n",
+        )
+        .unwrap();
+        assert_eq!(read_dotenv(&path, "AOC_TOKEN").as_deref(), Some("abc123"));
+        assert_eq!(
+            read_dotenv(&path, &var_name(Some("work"))).as_deref(),
+            Some("def456")
+        );
+        assert_eq!(read_dotenv(&path, &var_name(Some("home"))), None);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_profile_names() {
+        assert_eq!(var_name(Some("my-work.2")), "AOC_TOKEN_MY_WORK_2");
+        assert!(is_valid_profile("my-work"));
+        for profile in ["", "../other", "a/b", "a\\b", ".."] {
+            assert!(!is_valid_profile(profile), "{profile:?}");
+        }
+        assert_eq!(token_file(Some("../token")), None);
+    }
+}
@@ -0,0 +1,192 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// Source of time, so that waiting logic can be tested without actually waiting
+pub trait Clock {
+    fn now(&self) -> SystemTime;
+    fn sleep(&self, d: Duration);
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn sleep(&self, d: Duration) {
+        thread::sleep(d)
+    }
+}
+
+/// Sleep until `target`, calling `on_tick` with the remaining time about once per second
+pub fn wait_until(clock: &impl Clock, target: SystemTime, mut on_tick: impl FnMut(Duration)) {
+    while let Ok(remaining) = target.duration_since(clock.now()) {
+        if remaining.is_zero() {
+            break;
+        }
+        on_tick(remaining);
+        // sleep until the next whole second so the countdown stays aligned
+        let step = match remaining.subsec_nanos() {
+            0 => Duration::from_secs(1),
+            nanos => Duration::from_nanos(nanos as u64),
+        };
+        clock.sleep(step.min(remaining));
+    }
+}
+
+/// Exponential backoff delays, each randomly shortened by up to a quarter so that clients don't
+/// all retry at the same instant
+pub struct Backoff {
+    next: Duration,
+    max: Duration,
+    state: u64,
+}
+
+impl Backoff {
+    pub fn new(initial: Duration, max: Duration) -> Self {
+        Self::with_seed(initial, max, RandomState::new().build_hasher().finish())
+    }
+
+    pub fn with_seed(initial: Duration, max: Duration, seed: u64) -> Self {
+        Backoff {
+            next: initial,
+            max,
+            state: seed | 1,
+        }
+    }
+
+    fn next_random(&mut self) -> u64 {
+        // xorshift64
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+}
+
+impl Iterator for Backoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        let base = self.next;
+        self.next = (self.next * 2).min(self.max);
+        let jitter_range = base.as_millis() as u64 / 4;
+        let jitter = match jitter_range {
+            0 => 0,
+            range => self.next_random() % range,
+        };
+        Some(base - Duration::from_millis(jitter))
+    }
+}
+
+/// Call `f` until it succeeds, an error is not retryable, or `attempts` calls were made, sleeping
+/// according to `backoff` in between
+pub fn retry<T, E>(
+    clock: &impl Clock,
+    backoff: impl Iterator<Item = Duration>,
+    attempts: usize,
+    mut f: impl FnMut() -> Result<T, E>,
+    retryable: impl Fn(&E) -> bool,
+) -> Result<T, E> {
+    let mut delays = backoff.take(attempts.saturating_sub(1));
+    loop {
+        match f() {
+            Err(e) if retryable(&e) => match delays.next() {
+                Some(delay) => clock.sleep(delay),
+                None => return Err(e),
+            },
+            res => return res,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+    use std::time::UNIX_EPOCH;
+
+    use super::*;
+
+    struct FakeClock {
+        now: Cell<SystemTime>,
+        sleeps: RefCell<Vec<Duration>>,
+    }
+
+    impl FakeClock {
+        fn new(now: SystemTime) -> Self {
+            FakeClock {
+                now: Cell::new(now),
+                sleeps: RefCell::new(Vec::new()),
+            }
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> SystemTime {
+            self.now.get()
+        }
+
+        fn sleep(&self, d: Duration) {
+            self.now.set(self.now.get() + d);
+            self.sleeps.borrow_mut().push(d);
+        }
+    }
+
+    #[test]
+    fn test_wait_until() {
+        let start = UNIX_EPOCH + Duration::from_millis(1_000_500);
+        let clock = FakeClock::new(start);
+        let target = UNIX_EPOCH + Duration::from_secs(1003);
+        let mut ticks = Vec::new();
+        wait_until(&clock, target, |remaining| ticks.push(remaining.as_secs()));
+        assert_eq!(clock.now(), target);
+        assert_eq!(ticks, [2, 2, 1]);
+
+        // target in the past returns immediately
+        let mut ticks = 0;
+        wait_until(&clock, start, |_| ticks += 1);
+        assert_eq!(ticks, 0);
+    }
+
+    #[test]
+    fn test_backoff_retry() {
+        let delays = Backoff::with_seed(Duration::from_secs(1), Duration::from_secs(5), 42)
+            .take(5)
+            .collect::<Vec<_>>();
+        for (delay, base) in delays.iter().zip([1000, 2000, 4000, 5000, 5000]) {
+            assert!(delay.as_millis() <= base && delay.as_millis() >= base * 3 / 4);
+        }
+
+        let clock = FakeClock::new(UNIX_EPOCH);
+        let mut calls = 0;
+        let res: Result<u32, &str> = retry(
+            &clock,
+            delays.iter().copied(),
+            5,
+            || {
+                calls += 1;
+                if calls < 3 { Err("not yet") } else { Ok(calls) }
+            },
+            |e| *e == "not yet",
+        );
+        assert_eq!(res, Ok(3));
+        assert_eq!(clock.sleeps.borrow().as_slice(), &delays[..2]);
+
+        let mut calls = 0;
+        let res: Result<(), &str> = retry(
+            &clock,
+            delays.iter().copied(),
+            5,
+            || {
+                calls += 1;
+                Err("fatal")
+            },
+            |e| *e == "not yet",
+        );
+        assert_eq!(res, Err("fatal"));
+        assert_eq!(calls, 1);
+    }
+}
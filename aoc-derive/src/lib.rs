@@ -1,73 +1,248 @@
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{
     parse_macro_input, punctuated::Punctuated, spanned::Spanned, AngleBracketedGenericArguments,
-    AssocType, Expr, ExprLit, FnArg, GenericArgument, ItemFn, Lit, MetaNameValue, PatType, Path,
-    PathArguments, ReturnType, Signature, Token, TraitBound, Type, TypeImplTrait, TypeParamBound,
-    TypePath, TypeReference,
+    AssocType, Expr, ExprLit, FnArg, GenericArgument, Ident, Item, ItemFn, ItemMod, Lit, Meta,
+    MetaNameValue, PatType, Path, PathArguments, ReturnType, Signature, Token, TraitBound, Type,
+    TypeImplTrait, TypeParamBound, TypePath, TypeReference,
 };
 
 struct Attributes {
-    part: u8,
+    part: Option<u8>,
+    both: bool,
     example_result: Option<Lit>,
+    example_result2: Option<Lit>,
     bench_count: Option<u32>,
+    test_day: Option<Path>,
+    parsed_day: Option<Path>,
+    /// Values of the last argument on examples and on the real input
+    param: Option<(Expr, Expr)>,
 }
 
-fn attr_value<'a>(attrs: &'a Punctuated<MetaNameValue, Token![,]>, path: &str) -> Option<&'a Expr> {
-    attrs
-        .iter()
-        .find(|attr| attr.path.is_ident(path))
-        .map(|attr| &attr.value)
+fn attr_value<'a>(attrs: &'a Punctuated<Meta, Token![,]>, path: &str) -> Option<&'a Expr> {
+    attrs.iter().find_map(|attr| match attr {
+        Meta::NameValue(MetaNameValue { path: p, value, .. }) if p.is_ident(path) => Some(value),
+        _ => None,
+    })
 }
 
 fn required_attr_value<'a>(
-    attrs: &'a Punctuated<MetaNameValue, Token![,]>,
+    attrs: &'a Punctuated<Meta, Token![,]>,
     path: &str,
 ) -> syn::Result<&'a Expr> {
-    attr_value(attrs, path)
-        .ok_or_else(|| syn::Error::new(attrs.span(), format!("\"{path}\" attribute missing")))
+    attr_value(attrs, path).ok_or_else(|| {
+        let span = if attrs.is_empty() {
+            proc_macro2::Span::call_site()
+        } else {
+            attrs.span()
+        };
+        syn::Error::new(span, format!("\"{path}\" attribute missing"))
+    })
 }
 
-fn int_attr(attrs: &Punctuated<MetaNameValue, Token![,]>, path: &str) -> syn::Result<u64> {
+fn int_attr(attrs: &Punctuated<Meta, Token![,]>, path: &str) -> syn::Result<u64> {
+    let value = required_attr_value(attrs, path)?;
     let Expr::Lit(ExprLit {
-        lit: Lit::Int(day), ..
-    }) = required_attr_value(attrs, path)?
+        lit: Lit::Int(int), ..
+    }) = value
     else {
         return Err(syn::Error::new(
-            attrs.span(),
+            value.span(),
             format!("attribute \"{path}\" must be an integer"),
         ));
     };
-    day.base10_parse()
+    int.base10_parse()
 }
 
-fn parse_attrs(attrs: Punctuated<MetaNameValue, Token![,]>) -> syn::Result<Attributes> {
-    let part = int_attr(&attrs, "part")? as u8;
+/// Reject unknown and repeated attributes, `lists` are the ones written `name(...)`
+fn check_attr_names(
+    attrs: &Punctuated<Meta, Token![,]>,
+    values: &[&str],
+    lists: &[&str],
+) -> syn::Result<()> {
+    let mut seen = Vec::new();
+    for attr in attrs {
+        let path = attr.path();
+        let name = path
+            .get_ident()
+            .map(|ident| ident.to_string())
+            .unwrap_or_default();
+        if seen.contains(&name) {
+            return Err(syn::Error::new(
+                path.span(),
+                format!("duplicate attribute \"{name}\""),
+            ));
+        }
+        match attr {
+            Meta::NameValue(_) if values.contains(&name.as_str()) => (),
+            Meta::List(_) if lists.contains(&name.as_str()) => (),
+            _ if values.contains(&name.as_str()) => {
+                return Err(syn::Error::new(
+                    attr.span(),
+                    format!("expected `{name} = ...`"),
+                ))
+            }
+            _ if lists.contains(&name.as_str()) => {
+                return Err(syn::Error::new(
+                    attr.span(),
+                    format!("expected `{name}(...)`"),
+                ))
+            }
+            _ => {
+                let expected = values
+                    .iter()
+                    .chain(lists)
+                    .map(|name| format!("\"{name}\""))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                return Err(syn::Error::new(
+                    path.span(),
+                    format!("unknown attribute, expected one of {expected}"),
+                ));
+            }
+        }
+        seen.push(name);
+    }
+    Ok(())
+}
 
-    let example_result = attrs
-        .iter()
-        .find(|attr| attr.path.is_ident("example"))
-        .and_then(|attr| match &attr.value {
-            Expr::Lit(ExprLit { lit, .. }) => Some(lit.clone()),
-            _ => None,
-        });
+fn parse_attrs(attrs: Punctuated<Meta, Token![,]>) -> syn::Result<Attributes> {
+    check_attr_names(
+        &attrs,
+        &["part", "example", "benchmark", "test", "parse"],
+        &["param"],
+    )?;
+    let parsed_day = attr_value(&attrs, "parse")
+        .map(|attr| match attr {
+            Expr::Path(path) => Ok(path.path.clone()),
+            _ => Err(syn::Error::new(
+                attr.span(),
+                "attribute \"parse\" must be the type of the day",
+            )),
+        })
+        .transpose()?;
+    // the parser of a day has no part, a function solving both parts has no part number
+    let (part, both) =
+        match (&parsed_day, attr_value(&attrs, "part")) {
+            (Some(_), None) => (None, false),
+            (
+                _,
+                Some(Expr::Lit(ExprLit {
+                    lit: Lit::Str(s), ..
+                })),
+            ) if s.value() == "both" => (None, true),
+            (
+                _,
+                Some(
+                    value @ Expr::Lit(ExprLit {
+                        lit: Lit::Str(_), ..
+                    }),
+                ),
+            ) => {
+                return Err(syn::Error::new(
+                    value.span(),
+                    "attribute \"part\" must be 1, 2 or \"both\"",
+                ))
+            }
+            _ => match int_attr(&attrs, "part")? {
+                part @ (1 | 2) => (Some(part as u8), false),
+                _ => return Err(syn::Error::new(
+                    attr_value(&attrs, "part").span(),
+                    "Advent of Code puzzles have parts 1 and 2, implement `Part` for other parts",
+                )),
+            },
+        };
 
-    let bench_count = attr_value(&attrs, "benchmark").and_then(|attr| match &attr {
+    let lit = |expr: &Expr| match expr {
         Expr::Lit(ExprLit {
-            lit: Lit::Int(i), ..
-        }) => i.base10_parse().ok(),
-        _ => None,
-    });
+            lit: lit @ (Lit::Int(_) | Lit::Str(_)),
+            ..
+        }) => Ok(lit.clone()),
+        _ => Err(syn::Error::new(
+            expr.span(),
+            "example results must be integer or string literals",
+        )),
+    };
+    // `example = (1, 2)` gives the results of both parts
+    let (example_result, example_result2) = match attr_value(&attrs, "example") {
+        Some(Expr::Tuple(tuple)) if both && tuple.elems.len() == 2 => {
+            (Some(lit(&tuple.elems[0])?), Some(lit(&tuple.elems[1])?))
+        }
+        Some(expr @ Expr::Tuple(_)) => {
+            return Err(syn::Error::new(
+                expr.span(),
+                "a pair of example results needs part = \"both\"",
+            ))
+        }
+        Some(expr) => (Some(lit(expr)?), None),
+        None => (None, None),
+    };
+
+    let bench_count = attr_value(&attrs, "benchmark")
+        .map(|value| match both {
+            true => Err(syn::Error::new(
+                value.span(),
+                "\"benchmark\" is not supported when solving both parts",
+            )),
+            false => int_attr(&attrs, "benchmark").map(|count| count as u32),
+        })
+        .transpose()?;
+
+    let test_day = attr_value(&attrs, "test")
+        .map(|attr| match attr {
+            Expr::Path(path) => Ok(path.path.clone()),
+            _ => Err(syn::Error::new(
+                attr.span(),
+                "attribute \"test\" must be the type of the day",
+            )),
+        })
+        .transpose()?;
+
+    // `param(example = 7, real = 71)`
+    let param = attrs
+        .iter()
+        .find_map(|attr| match attr {
+            Meta::List(list) if list.path.is_ident("param") => Some(list),
+            _ => None,
+        })
+        .map(|list| {
+            let values = list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+            check_attr_names(&values, &["example", "real"], &[])?;
+            let example = required_attr_value(&values, "example")?.clone();
+            let real = required_attr_value(&values, "real")?.clone();
+            syn::Result::Ok((example, real))
+        })
+        .transpose()?;
 
     Ok(Attributes {
         part,
+        both,
         example_result,
+        example_result2,
         bench_count,
+        test_day,
+        parsed_day,
+        param,
     })
 }
 
-fn get_iterator_item(ty: &Path) -> Option<&Path> {
-    let seg = ty.segments.first()?;
+/// Type of the first argument, which receives the input
+fn input_type(sig: &Signature) -> syn::Result<&Type> {
+    match sig.inputs.first() {
+        Some(FnArg::Typed(PatType { ty, .. })) => Ok(ty),
+        Some(arg) => Err(syn::Error::new(
+            arg.span(),
+            "the function must take the input as its first argument",
+        )),
+        None => Err(syn::Error::new(
+            sig.paren_token.span.join(),
+            "the function must take the input as its first argument",
+        )),
+    }
+}
+
+fn get_iterator_item(ty: &Path) -> Option<&Type> {
+    let seg = ty.segments.last()?;
     if seg.ident != "Iterator" {
         return None;
     }
@@ -75,18 +250,14 @@ fn get_iterator_item(ty: &Path) -> Option<&Path> {
     else {
         return None;
     };
-    let GenericArgument::AssocType(AssocType {
-        ty: Type::Path(TypePath { path, .. }),
-        ..
-    }) = args.first().as_ref()?
-    else {
+    let GenericArgument::AssocType(AssocType { ty, .. }) = args.first()? else {
         return None;
     };
-    Some(path)
+    Some(ty)
 }
 
-fn get_vec_item(ty: &Path) -> Option<&Path> {
-    let seg = ty.segments.first()?;
+fn get_vec_item(ty: &Path) -> Option<&Type> {
+    let seg = ty.segments.last()?;
     if seg.ident != "Vec" {
         return None;
     }
@@ -94,66 +265,357 @@ fn get_vec_item(ty: &Path) -> Option<&Path> {
     else {
         return None;
     };
-    let GenericArgument::Type(Type::Path(TypePath { path, .. })) = args.first().as_ref()? else {
+    let GenericArgument::Type(ty) = args.first()? else {
         return None;
     };
-    Some(path)
+    Some(ty)
+}
+
+fn is_type(ty: &Type, ident: &str) -> bool {
+    matches!(ty, Type::Path(TypePath { path, .. }) if path.is_ident(ident))
 }
 
-fn convert_bufread(ty: &Type) -> syn::Result<proc_macro2::TokenStream> {
+/// Whether `path` is the type `ident` of the module `module` of one of `crates`, either imported
+/// or as `{crate}::{module}::{ident}`, with generic arguments accepted by `args`
+fn is_framework_type(
+    path: &Path,
+    crates: &[&str],
+    module: &str,
+    ident: &str,
+    args: impl Fn(&PathArguments) -> bool,
+) -> bool {
+    let segments = path.segments.iter().collect::<Vec<_>>();
+    let prefix_matches = match segments.as_slice() {
+        [_] => true,
+        [m, _] => m.ident == module,
+        [c, m, _] => crates.iter().any(|name| c.ident == name) && m.ident == module,
+        _ => false,
+    };
+    let last = segments.last().unwrap();
+    prefix_matches && last.ident == ident && args(&last.arguments)
+}
+
+/// `Input` or `Input<'a>`
+fn is_input_args(args: &PathArguments) -> bool {
+    match args {
+        PathArguments::None => true,
+        PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }) => matches!(
+            args.iter().collect::<Vec<_>>().as_slice(),
+            [GenericArgument::Lifetime(_)]
+        ),
+        _ => false,
+    }
+}
+
+/// `Grid<u8, 2>`, the only grid built from the input
+fn is_grid_args(args: &PathArguments) -> bool {
+    let PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }) = args else {
+        return false;
+    };
+    matches!(
+        args.iter().collect::<Vec<_>>().as_slice(),
+        [
+            GenericArgument::Type(item),
+            GenericArgument::Const(Expr::Lit(ExprLit { lit: Lit::Int(n), .. })),
+        ] if is_type(item, "u8") && n.base10_digits() == "2"
+    )
+}
+
+fn is_vec_of(ty: &Type, ident: &str) -> bool {
+    matches!(ty, Type::Path(TypePath { path, .. })
+        if get_vec_item(path).is_some_and(|item| is_type(item, ident)))
+}
+
+fn is_slice_of(ty: &Type, ident: &str) -> bool {
+    matches!(ty, Type::Slice(slice) if is_type(&slice.elem, ident))
+}
+
+/// Conversion of one blank-line separated section, for tuples of sections
+fn convert_section(
+    ty: &Type,
+    section: proc_macro2::TokenStream,
+) -> Option<proc_macro2::TokenStream> {
+    if is_type(ty, "String") {
+        return Some(quote!(#section.join("\n")));
+    }
+    let Type::Path(TypePath { path, .. }) = ty else {
+        return None;
+    };
+    match get_vec_item(path)? {
+        item if is_type(item, "String") => Some(section),
+        item @ Type::Path(_) => {
+            Some(quote!(aoc_framework::input::parse_section::<#item>(#section)))
+        }
+        _ => None,
+    }
+}
+
+/// Conversion of `input`, an `aoc_framework::input::Input`, to the argument type `ty`. Borrowed
+/// types borrow the input without copying it.
+fn convert_input(ty: &Type) -> syn::Result<proc_macro2::TokenStream> {
     match ty {
         Type::ImplTrait(TypeImplTrait { bounds, .. }) => {
             if let Some(TypeParamBound::Trait(TraitBound { path, .. })) = bounds.first() {
                 if path.is_ident("BufRead") {
-                    return Ok(quote!(input));
+                    return Ok(quote!(input.reader()));
                 }
-                if let Some(item) = get_iterator_item(path) {
-                    if item.is_ident("String") {
-                        return Ok(quote!(input.lines().map(|ln| ln.unwrap())));
+                match get_iterator_item(path) {
+                    Some(item) if is_type(item, "String") => {
+                        return Ok(quote!(input.lines().map(str::to_string)));
+                    }
+                    Some(item) if is_type(item, "u8") => {
+                        return Ok(quote!(input.as_bytes().iter().copied()));
+                    }
+                    Some(Type::Path(TypePath { path, .. })) if get_vec_item(path).is_some() => {
+                        return Ok(quote!(input.byte_lines().map(<[u8]>::to_vec)));
                     }
-                    if item.is_ident("u8") {
-                        return Ok(quote!(input.bytes().map(|b| b.unwrap())));
+                    Some(Type::Reference(TypeReference { elem, .. })) if is_type(elem, "str") => {
+                        return Ok(quote!(input.lines()));
                     }
-                    if item.segments.last().unwrap().ident == "Vec" {
-                        return Ok(quote!(input.split(b'\n').map(|ln| ln.unwrap())));
+                    Some(Type::Reference(TypeReference { elem, .. }))
+                        if is_slice_of(elem, "u8") =>
+                    {
+                        return Ok(quote!(input.byte_lines()));
                     }
+                    Some(item @ Type::Path(_)) => {
+                        return Ok(quote!(input.parse_lines::<#item>()));
+                    }
+                    _ => (),
                 }
             }
         }
         Type::Path(TypePath { path, .. }) => {
-            if let Some(item) = get_vec_item(path) {
-                if item.is_ident("String") {
+            match get_vec_item(path) {
+                Some(item) if is_type(item, "String") => {
                     return Ok(quote!(input
                         .lines()
-                        .collect::<Result<Vec<_>, _>>()
-                        .unwrap()));
+                        .map(str::to_string)
+                        .collect::<Vec<_>>()));
+                }
+                Some(Type::Reference(TypeReference { elem, .. })) if is_type(elem, "str") => {
+                    return Ok(quote!(input.lines().collect::<Vec<_>>()));
                 }
-                if item.is_ident("u8") {
+                Some(item) if is_type(item, "u8") => {
+                    return Ok(quote!(input.as_bytes().to_vec()));
+                }
+                Some(item) if is_vec_of(item, "u8") => {
                     return Ok(quote!(input
-                        .bytes()
-                        .collect::<Result<Vec<_>, _>>()
-                        .unwrap()));
+                        .byte_lines()
+                        .map(<[u8]>::to_vec)
+                        .collect::<Vec<_>>()));
+                }
+                Some(item) if is_vec_of(item, "String") => {
+                    return Ok(quote!(input.section_lines().collect::<Vec<_>>()));
                 }
+                Some(item @ Type::Path(_)) => {
+                    return Ok(quote!(input.parse_lines::<#item>().collect::<Vec<_>>()));
+                }
+                _ => (),
+            }
+            if is_framework_type(
+                path,
+                &["aoc_framework", "aoc_base"],
+                "input",
+                "Input",
+                is_input_args,
+            ) {
+                return Ok(quote!(input));
+            }
+            if is_framework_type(path, &["aoc_framework"], "grid", "Grid", is_grid_args) {
+                return Ok(quote!(aoc_framework::grid::Grid::from_bytes(
+                    input.as_bytes().to_vec()
+                )));
             }
         }
         Type::Reference(TypeReference { elem, .. }) => match elem.as_ref() {
-            Type::Path(TypePath { path, .. }) if path.is_ident("str") => {
-                return Ok(quote!(&{
-                    let mut out = String::new();
-                    input.read_to_string(&mut out).unwrap();
-                    out
-                }))
-            }
+            elem if is_type(elem, "str") => return Ok(quote!(input.as_str())),
+            elem if is_slice_of(elem, "u8") => return Ok(quote!(input.as_bytes())),
             _ => (),
         },
+        Type::Tuple(tuple) => {
+            let sections = tuple
+                .elems
+                .iter()
+                .enumerate()
+                .map(|(n, ty)| {
+                    let section = quote!(aoc_framework::input::take_section(&mut sections, #n));
+                    convert_section(ty, section).ok_or_else(|| {
+                        syn::Error::new(
+                            ty.span(),
+                            "Sections must be String, Vec<String> or Vec<T> where T: FromStr",
+                        )
+                    })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            return Ok(quote!({
+                let mut sections = input.section_lines();
+                (#(#sections,)*)
+            }));
+        }
         _ => (),
     }
     Err(syn::Error::new(
         ty.span(),
-        "Supported types are Vec<String>, Vec<u8>, impl Iterator<String>, impl Iterator<Item = u8> and BufRead",
+        "Supported types are Input, BufRead, &str, &[u8], Vec<u8>, Vec<String>, Vec<&str>, \
+        Vec<Vec<u8>>, Vec<T> where T: FromStr, sections as Vec<Vec<String>> or a tuple, \
+        Grid<u8, 2>, and impl Iterator<Item = ..> of String, &str, u8, Vec<u8>, &[u8] or T \
+        where T: FromStr",
     ))
 }
 
+fn example_const(example_result: Option<Lit>) -> Option<proc_macro2::TokenStream> {
+    example_result
+        .map(|res| match res {
+            Lit::Str(s) => {
+                quote!(Some(aoc_framework::Str(std::borrow::Cow::Borrowed(#s))))
+            }
+            Lit::Int(i) => quote!(Some(aoc_framework::Num(#i))),
+            _ => quote!(None),
+        })
+        .map(|val| quote!(const EXAMPLE_RESULT: Option<aoc_framework::Answer> = #val;))
+}
+
+/// Value of `param(...)` for the run `run` in scope, passed after the other arguments
+fn param_arg(param: &Option<(Expr, Expr)>) -> proc_macro2::TokenStream {
+    match param {
+        Some((example, real)) => quote!(, aoc_framework::Param {
+            example: #example,
+            real: #real,
+        }
+        .get(run)),
+        None => quote!(),
+    }
+}
+
+/// First type argument of `ty` if its name is `name`, e.g. the `T` of `Option<T>`
+fn generic_arg<'a>(ty: &'a Type, name: &str) -> Option<&'a Type> {
+    let Type::Path(TypePath { path, .. }) = ty else {
+        return None;
+    };
+    let seg = path.segments.last()?;
+    if seg.ident != name {
+        return None;
+    }
+    let PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }) = &seg.arguments
+    else {
+        return None;
+    };
+    match args.first()? {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    }
+}
+
+/// Conversion of `res` to an `Answer`, with `From` for the types that have it and through
+/// `Display` otherwise
+fn convert_answer(
+    ty: &Type,
+    res: proc_macro2::TokenStream,
+) -> syn::Result<proc_macro2::TokenStream> {
+    const FROM: &[&str] = &[
+        "u8", "u16", "u32", "u64", "usize", "i8", "i16", "i32", "i64", "isize", "char", "String",
+        "Answer",
+    ];
+    match ty {
+        Type::Path(TypePath { path, .. })
+            if FROM.contains(&path.segments.last().unwrap().ident.to_string().as_str()) =>
+        {
+            Ok(quote!(aoc_framework::Answer::from(#res)))
+        }
+        Type::Reference(TypeReference { elem, .. }) if is_type(elem, "str") => {
+            Ok(quote!(aoc_framework::Answer::from(#res)))
+        }
+        Type::Tuple(tuple) if tuple.elems.is_empty() => Err(syn::Error::new(
+            ty.span(),
+            "the function must return the answer",
+        )),
+        Type::Tuple(_) => Err(syn::Error::new(
+            ty.span(),
+            "a tuple of answers needs part = \"both\"",
+        )),
+        _ => Ok(quote!(aoc_framework::Answer::from_display(&#res))),
+    }
+}
+
+/// Conversion of `res`, of type `ty`, to an `anyhow::Result` of the answer converted by `answer`.
+/// `Result` and `Option` can be nested, `None` meaning that there is no answer.
+fn convert_nested(
+    fn_ident: &Ident,
+    ty: &Type,
+    res: proc_macro2::TokenStream,
+    answer: &dyn Fn(&Type, proc_macro2::TokenStream) -> syn::Result<proc_macro2::TokenStream>,
+) -> syn::Result<proc_macro2::TokenStream> {
+    if let Some(ok) = generic_arg(ty, "Result") {
+        let ok = convert_nested(fn_ident, ok, quote!(res), answer)?;
+        let context = format!("{fn_ident} failed");
+        return Ok(quote!(match #res {
+            Ok(res) => #ok,
+            Err(e) => Err(aoc_framework::anyhow::anyhow!(e).context(#context)),
+        }));
+    }
+    if let Some(some) = generic_arg(ty, "Option") {
+        let some = convert_nested(fn_ident, some, quote!(res), answer)?;
+        let none = format!("{fn_ident} returned no answer");
+        return Ok(quote!(match #res {
+            Some(res) => #some,
+            None => Err(aoc_framework::anyhow::anyhow!(#none)),
+        }));
+    }
+    answer(ty, res)
+}
+
+fn convert_single(
+    fn_ident: &Ident,
+    ty: &Type,
+    res: proc_macro2::TokenStream,
+) -> syn::Result<proc_macro2::TokenStream> {
+    convert_nested(fn_ident, ty, res, &|ty, res| {
+        let answer = convert_answer(ty, res)?;
+        Ok(quote!(aoc_framework::anyhow::Ok(#answer)))
+    })
+}
+
+/// `anyhow::Result<Answer>` from `res`, the result of the function
+fn convert_output(sig: &Signature) -> syn::Result<proc_macro2::TokenStream> {
+    let ReturnType::Type(_, ty) = &sig.output else {
+        return Err(syn::Error::new(
+            sig.ident.span(),
+            "the function must return the answer",
+        ));
+    };
+    convert_single(&sig.ident, ty, quote!(res))
+}
+
+/// `anyhow::Result<(Answer, Answer)>` from `res`, the result of a function solving both parts
+fn convert_both_output(sig: &Signature) -> syn::Result<proc_macro2::TokenStream> {
+    let ReturnType::Type(_, ty) = &sig.output else {
+        return Err(syn::Error::new(
+            sig.ident.span(),
+            "the function must return the answers of both parts",
+        ));
+    };
+    convert_nested(&sig.ident, ty, quote!(res), &|ty, res| {
+        let Type::Tuple(tuple) = ty else {
+            return Err(syn::Error::new(
+                ty.span(),
+                "the function must return a tuple of the answers of both parts",
+            ));
+        };
+        let [ty1, ty2] = tuple.elems.iter().collect::<Vec<_>>()[..] else {
+            return Err(syn::Error::new(
+                ty.span(),
+                "the function must return a tuple of the answers of both parts",
+            ));
+        };
+        let part1 = convert_single(&sig.ident, ty1, quote!(part1))?;
+        let part2 = convert_single(&sig.ident, ty2, quote!(part2))?;
+        Ok(quote!({
+            let (part1, part2) = #res;
+            (#part1).and_then(|part1| aoc_framework::anyhow::Ok((part1, #part2?)))
+        }))
+    })
+}
+
 fn returns_result(sig: &Signature) -> bool {
     let ReturnType::Type(_, ty) = &sig.output else {
         return false;
@@ -164,6 +626,292 @@ fn returns_result(sig: &Signature) -> bool {
     path.segments.last().unwrap().ident == "Result"
 }
 
+/// Type returned by a function, or its `Ok` type if it returns a `Result`
+fn output_type(sig: &Signature) -> syn::Result<Type> {
+    let ReturnType::Type(_, ty) = &sig.output else {
+        return Err(syn::Error::new(
+            sig.ident.span(),
+            "the parser must return the parsed input",
+        ));
+    };
+    if !returns_result(sig) {
+        return Ok(ty.as_ref().clone());
+    }
+    let Type::Path(TypePath { path, .. }) = ty.as_ref() else {
+        unreachable!()
+    };
+    match &path.segments.last().unwrap().arguments {
+        PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }) => {
+            match args.first() {
+                Some(GenericArgument::Type(ty)) => Ok(ty.clone()),
+                _ => Err(syn::Error::new(ty.span(), "missing Ok type")),
+            }
+        }
+        _ => Err(syn::Error::new(ty.span(), "missing Ok type")),
+    }
+}
+
+fn impl_parser(function: ItemFn, day: Path) -> syn::Result<proc_macro2::TokenStream> {
+    let sig = &function.sig;
+    let fn_ident = &sig.ident;
+    let conversion = convert_input(input_type(sig)?)?;
+    let output = output_type(sig)?;
+    let res = if returns_result(sig) {
+        let context = format!("{fn_ident} failed");
+        quote!(res.map_err(|e| aoc_framework::anyhow::anyhow!(e).context(#context)))
+    } else {
+        quote!(Ok(res))
+    };
+    Ok(quote!(
+        impl aoc_framework::ParsedDay for #day {
+            type Input = #output;
+
+            fn parse(
+                input: aoc_framework::input::Input<'_>,
+            ) -> aoc_framework::anyhow::Result<#output> {
+                let res = #fn_ident(#conversion);
+                #res
+            }
+        }
+        #function
+    ))
+}
+
+/// `run` and `run_shared` of a part taking the input parsed by `day`, and optionally the answer
+/// of part 1
+fn impl_parsed_run(
+    sig: &Signature,
+    day: &Path,
+    part: u8,
+    bench_count: Option<u32>,
+    param: &Option<(Expr, Expr)>,
+    result_conv: &proc_macro2::TokenStream,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let fn_ident = &sig.ident;
+    input_type(sig)?;
+    let param_arg = param_arg(param);
+    let run_part1 = quote!(
+        <<#day as aoc_framework::Day>::Part1 as aoc_framework::Part>::run_with(input, shared, run)
+    );
+    let (part1, args) = match sig.inputs.len() - param.is_some() as usize {
+        1 => (quote!(), quote!(&parsed #param_arg)),
+        2 if part > 1 => (
+            quote!(
+            let part1 = match shared.answer(1) {
+                Some(answer) => answer,
+                None => #run_part1?,
+            };
+            ),
+            quote!(&parsed, part1.clone() #param_arg),
+        ),
+        _ => {
+            return Err(syn::Error::new(
+                sig.inputs.span(),
+                "parts of a parsed day take the parsed input, and the answer of part 1 after it",
+            ))
+        }
+    };
+    let bench = bench_count.map(|count| {
+        quote!(
+        #[allow(unused_variables)]
+        fn bench(input: aoc_framework::input::Input<'_>) -> Option<std::time::Duration> {
+            let (input, shared) = (input.as_str(), &aoc_framework::SharedInput::default());
+            let run = aoc_framework::Run::Real;
+            let prepare = || -> aoc_framework::anyhow::Result<_> {
+                let parsed = shared.get::<#day>(input)?;
+                #part1
+                Ok(move || #fn_ident(#args))
+            };
+            let solve = prepare().ok()?;
+            let start = std::time::Instant::now();
+            for _ in 0..#count {
+                _ = solve();
+            }
+            Some(start.elapsed() / #count)
+        }
+        )
+    });
+    Ok(quote!(
+        fn run(input: impl std::io::BufRead) -> aoc_framework::anyhow::Result<aoc_framework::Answer> {
+            Self::run_shared(
+                &aoc_framework::input::read_string(input)?,
+                &aoc_framework::SharedInput::default(),
+            )
+        }
+
+        fn run_input(
+            input: aoc_framework::input::Input<'_>,
+        ) -> aoc_framework::anyhow::Result<aoc_framework::Answer> {
+            Self::run_shared(input.as_str(), &aoc_framework::SharedInput::default())
+        }
+
+        fn run_shared(
+            input: &str,
+            shared: &aoc_framework::SharedInput,
+        ) -> aoc_framework::anyhow::Result<aoc_framework::Answer> {
+            Self::run_with(input, shared, aoc_framework::Run::Real)
+        }
+
+        #[allow(unused_variables)]
+        fn run_with(
+            input: &str,
+            shared: &aoc_framework::SharedInput,
+            run: aoc_framework::Run,
+        ) -> aoc_framework::anyhow::Result<aoc_framework::Answer> {
+            let parsed = shared.get::<#day>(input)?;
+            #part1
+            let res = #fn_ident(#args);
+            #result_conv
+        }
+
+        #bench
+    ))
+}
+
+/// `Part1` and `Part2` in a module named after a function returning the answers of both parts,
+/// the function runs once and part 2 reuses its answer
+fn impl_both(function: ItemFn, attrs: Attributes) -> syn::Result<proc_macro2::TokenStream> {
+    let sig = &function.sig;
+    let fn_ident = &sig.ident;
+    let Attributes {
+        example_result,
+        example_result2,
+        test_day,
+        parsed_day,
+        param,
+        ..
+    } = attrs;
+    let param_arg = param_arg(&param);
+    let example1 = example_const(example_result);
+    let example2 = example_const(example_result2);
+    let solve = match parsed_day {
+        Some(day) => quote!(let res = super::#fn_ident(&shared.get::<#day>(input)? #param_arg);),
+        None => {
+            let conversion = convert_input(input_type(sig)?)?;
+            quote!(
+            let res = {
+                let input = aoc_framework::input::Input::new(input);
+                super::#fn_ident(#conversion #param_arg)
+            };
+            )
+        }
+    };
+    let answers = convert_both_output(sig)?;
+    let tests = test_day.map(|day| {
+        let mod_ident = format_ident!("{}_tests", fn_ident);
+        quote!(
+        #[cfg(test)]
+        mod #mod_ident {
+            use super::*;
+
+            #[test]
+            fn part1_example() {
+                aoc_framework::testing::check_example::<#day, #fn_ident::Part1>().unwrap();
+            }
+
+            #[test]
+            fn part2_example() {
+                aoc_framework::testing::check_example::<#day, #fn_ident::Part2>().unwrap();
+            }
+
+            #[test]
+            fn part1_answer() {
+                aoc_framework::testing::check_answer::<#day, #fn_ident::Part1>().unwrap();
+            }
+
+            #[test]
+            fn part2_answer() {
+                aoc_framework::testing::check_answer::<#day, #fn_ident::Part2>().unwrap();
+            }
+        }
+        )
+    });
+    Ok(quote!(
+    #[doc(hidden)]
+    pub mod #fn_ident {
+        #[allow(unused_imports)]
+        use super::*;
+
+        pub struct Part1 {}
+        pub struct Part2 {}
+
+        /// Answer of part 1, the answer of part 2 is recorded for `Part2`
+        #[allow(unused_variables)]
+        fn solve(
+            input: &str,
+            shared: &aoc_framework::SharedInput,
+            run: aoc_framework::Run,
+        ) -> aoc_framework::anyhow::Result<aoc_framework::Answer> {
+            #solve
+            let (part1, part2) = #answers?;
+            shared.record_answer(2, &part2);
+            Ok(part1)
+        }
+
+        impl aoc_framework::Part for Part1 {
+            const N: u8 = 1;
+            #example1
+
+            fn run(input: impl std::io::BufRead) -> aoc_framework::anyhow::Result<aoc_framework::Answer> {
+                Self::run_shared(
+                    &aoc_framework::input::read_string(input)?,
+                    &aoc_framework::SharedInput::default(),
+                )
+            }
+
+            fn run_shared(
+                input: &str,
+                shared: &aoc_framework::SharedInput,
+            ) -> aoc_framework::anyhow::Result<aoc_framework::Answer> {
+                Self::run_with(input, shared, aoc_framework::Run::Real)
+            }
+
+            fn run_with(
+                input: &str,
+                shared: &aoc_framework::SharedInput,
+                run: aoc_framework::Run,
+            ) -> aoc_framework::anyhow::Result<aoc_framework::Answer> {
+                solve(input, shared, run)
+            }
+        }
+
+        impl aoc_framework::Part for Part2 {
+            const N: u8 = 2;
+            const COMBINED_WITH: Option<u8> = Some(1);
+            #example2
+
+            fn run(input: impl std::io::BufRead) -> aoc_framework::anyhow::Result<aoc_framework::Answer> {
+                Self::run_shared(
+                    &aoc_framework::input::read_string(input)?,
+                    &aoc_framework::SharedInput::default(),
+                )
+            }
+
+            fn run_shared(
+                input: &str,
+                shared: &aoc_framework::SharedInput,
+            ) -> aoc_framework::anyhow::Result<aoc_framework::Answer> {
+                Self::run_with(input, shared, aoc_framework::Run::Real)
+            }
+
+            fn run_with(
+                input: &str,
+                shared: &aoc_framework::SharedInput,
+                run: aoc_framework::Run,
+            ) -> aoc_framework::anyhow::Result<aoc_framework::Answer> {
+                if let Some(answer) = shared.answer(2) {
+                    return Ok(answer);
+                }
+                solve(input, shared, run)?;
+                Ok(shared.answer(2).expect("part 2 is recorded by solve"))
+            }
+        }
+    }
+    #function
+    #tests
+    ))
+}
+
 fn impl_part(function: ItemFn, attrs: Attributes) -> syn::Result<proc_macro2::TokenStream> {
     let sig = &function.sig;
     let fn_ident = &sig.ident;
@@ -171,34 +919,79 @@ fn impl_part(function: ItemFn, attrs: Attributes) -> syn::Result<proc_macro2::To
         part,
         example_result,
         bench_count,
+        test_day,
+        parsed_day,
+        param,
+        ..
     } = attrs;
-    let example_const = example_result
-        .map(|res| match res {
-            Lit::Str(s) => {
-                quote!(Some(aoc_framework::Str(std::borrow::Cow::Borrowed(#s))))
+    let part = part.expect("only the parser has no part");
+    let param_arg = param_arg(&param);
+    let example_const = example_const(example_result);
+    let result_conv = convert_output(sig)?;
+    let run = match &parsed_day {
+        Some(day) => impl_parsed_run(sig, day, part, bench_count, &param, &result_conv)?,
+        None => {
+            let conversion = convert_input(input_type(sig)?)?;
+            let bench = if let Some(count) = bench_count {
+                quote!(
+                #[allow(unused_variables)]
+                fn bench(input: aoc_framework::input::Input<'_>) -> Option<std::time::Duration> {
+                    let run = aoc_framework::Run::Real;
+                    let converted = #conversion;
+                    let start = std::time::Instant::now();
+                    for _ in 0..#count {
+                        _ = #fn_ident(&converted #param_arg);
+                    }
+                    Some(start.elapsed() / #count)
+                }
+                )
+            } else {
+                quote!()
+            };
+            quote!(
+            fn run(input: impl std::io::BufRead) -> aoc_framework::anyhow::Result<aoc_framework::Answer> {
+                Self::run_input(aoc_framework::input::Input::new(
+                    &aoc_framework::input::read_string(input)?,
+                ))
             }
-            Lit::Int(i) => quote!(Some(aoc_framework::Num(#i))),
-            _ => quote!(None),
-        })
-        .map(|val| quote!(const EXAMPLE_RESULT: Option<aoc_framework::Answer> = #val;));
-    let Some(FnArg::Typed(PatType { ty, .. })) = sig.inputs.first() else {
-        panic!()
-    };
-    let conversion = convert_bufread(ty)?;
-    let result_conv = if returns_result(sig) {
-        quote!(res.map(|res| res.into()))
-    } else {
-        quote!(Ok(res.into()))
+
+            fn run_input(
+                input: aoc_framework::input::Input<'_>,
+            ) -> aoc_framework::anyhow::Result<aoc_framework::Answer> {
+                Self::run_with(input.as_str(), &aoc_framework::SharedInput::default(), aoc_framework::Run::Real)
+            }
+
+            #[allow(unused_variables)]
+            fn run_with(
+                input: &str,
+                shared: &aoc_framework::SharedInput,
+                run: aoc_framework::Run,
+            ) -> aoc_framework::anyhow::Result<aoc_framework::Answer> {
+                let input = aoc_framework::input::Input::new(input);
+                let res = #fn_ident(#conversion #param_arg);
+                #result_conv
+            }
+
+            #bench
+            )
+        }
     };
-    let bench = if let Some(count) = bench_count {
+    let tests = if let Some(day) = test_day {
+        let mod_ident = format_ident!("{}_tests", fn_ident);
         quote!(
-        fn bench(mut input: impl std::io::BufRead) -> Option<std::time::Duration> {
-            let converted = #conversion;
-            let start = std::time::Instant::now();
-            for _ in 0..#count {
-                _ = #fn_ident(&converted);
+        #[cfg(test)]
+        mod #mod_ident {
+            use super::*;
+
+            #[test]
+            fn example() {
+                aoc_framework::testing::check_example::<#day, #fn_ident>().unwrap();
+            }
+
+            #[test]
+            fn answer() {
+                aoc_framework::testing::check_answer::<#day, #fn_ident>().unwrap();
             }
-            Some(start.elapsed() / #count)
         }
         )
     } else {
@@ -212,27 +1005,255 @@ fn impl_part(function: ItemFn, attrs: Attributes) -> syn::Result<proc_macro2::To
         const N: u8 = #part;
         #example_const
 
-        fn run(mut input: impl std::io::BufRead) -> anyhow::Result<aoc_framework::Answer> {
-            let res = #fn_ident(#conversion);
-            #result_conv
-        }
-
-        #bench
+        #run
     }
     #function
+    #tests
     ))
 }
 
 #[proc_macro_attribute]
 pub fn aoc(attr: TokenStream, input: TokenStream) -> TokenStream {
     let function = parse_macro_input!(input as ItemFn);
-    let attr_list =
-        parse_macro_input!(attr with Punctuated<MetaNameValue, Token![,]>::parse_terminated);
+    let attr_list = parse_macro_input!(attr with Punctuated<Meta, Token![,]>::parse_terminated);
     let attrs = match parse_attrs(attr_list) {
         Ok(attrs) => attrs,
         Err(e) => return e.to_compile_error().into(),
     };
-    impl_part(function, attrs)
+    let res = match (attrs.part, attrs.parsed_day.clone()) {
+        _ if attrs.both => impl_both(function, attrs),
+        (None, Some(day)) => impl_parser(function, day),
+        _ => impl_part(function, attrs),
+    };
+    res.unwrap_or_else(|e| e.to_compile_error()).into()
+}
+
+/// Name of the day type generated for a module, e.g. `Day01` for `day01`
+fn day_ident(module: &Ident) -> Ident {
+    let name = module
+        .to_string()
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect::<String>();
+    Ident::new(&name, module.span())
+}
+
+/// Part number of a function of the module from its `#[aoc]` attribute, 0 if it solves both
+/// parts, with the span of the part number
+fn aoc_fn_part(function: &ItemFn) -> syn::Result<Option<(u64, proc_macro2::Span, &Ident)>> {
+    let Some(attr) = function.attrs.iter().find(|attr| {
+        attr.path()
+            .segments
+            .last()
+            .is_some_and(|seg| seg.ident == "aoc")
+    }) else {
+        return Ok(None);
+    };
+    let attrs = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+    let (part, span) = match attr_value(&attrs, "part") {
+        None => return Ok(None),
+        Some(Expr::Lit(ExprLit {
+            lit: Lit::Str(s), ..
+        })) if s.value() == "both" => (0, s.span()),
+        Some(value) => (int_attr(&attrs, "part")?, value.span()),
+    };
+    Ok(Some((part, span, &function.sig.ident)))
+}
+
+fn impl_day_mod(
+    mut module: ItemMod,
+    args: Punctuated<Expr, Token![,]>,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let mut args = args.into_iter();
+    let mut int_arg = |what: &str| match args.next() {
+        Some(Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        })) => int.base10_parse::<u64>(),
+        Some(expr) => Err(syn::Error::new(
+            expr.span(),
+            format!("expected the {what} as an integer"),
+        )),
+        None => Err(syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "expected the year and the day, e.g. #[aoc_day(2023, 1)]",
+        )),
+    };
+    let (year, day) = (int_arg("year")? as u16, int_arg("day")? as u8);
+    let (mut example, mut example2) = (quote!(None), quote!(None));
+    for arg in args {
+        let Expr::Assign(assign) = &arg else {
+            return Err(syn::Error::new(arg.span(), "expected `example = ...`"));
+        };
+        let value = &assign.right;
+        match assign.left.as_ref() {
+            Expr::Path(path) if path.path.is_ident("example") => example = quote!(Some(#value)),
+            Expr::Path(path) if path.path.is_ident("example2") => example2 = quote!(Some(#value)),
+            left => {
+                return Err(syn::Error::new(
+                    left.span(),
+                    "unknown attribute, expected one of \"example\", \"example2\"",
+                ))
+            }
+        }
+    }
+
+    let mod_ident = &module.ident;
+    let day_ident = day_ident(mod_ident);
+    let Some((_, items)) = &mut module.content else {
+        return Err(syn::Error::new(
+            module.span(),
+            "#[aoc_day] needs the parts in an inline module",
+        ));
+    };
+    let (mut part1, mut part2) = (None, None);
+    for item in items.iter() {
+        let Item::Fn(function) = item else {
+            continue;
+        };
+        let Some((part, span, fn_ident)) = aoc_fn_part(function)? else {
+            continue;
+        };
+        let parts = match part {
+            0 => vec![
+                (&mut part1, quote!(#mod_ident::#fn_ident::Part1)),
+                (&mut part2, quote!(#mod_ident::#fn_ident::Part2)),
+            ],
+            1 => vec![(&mut part1, quote!(#mod_ident::#fn_ident))],
+            2 => vec![(&mut part2, quote!(#mod_ident::#fn_ident))],
+            _ => {
+                return Err(syn::Error::new(
+                    span,
+                    "#[aoc_day] days have parts 1 and 2, or \"both\"",
+                ))
+            }
+        };
+        for (slot, path) in parts {
+            if slot.replace(path).is_some() {
+                return Err(syn::Error::new(
+                    fn_ident.span(),
+                    format!("{day_ident} already has a function for this part"),
+                ));
+            }
+        }
+    }
+    let part1 = part1.unwrap_or(quote!(()));
+    let part2 = part2.unwrap_or(quote!(()));
+    // so that the parts can name the day, e.g. in `test = Day01`, and `aoc_main!` finds it
+    // next to the other days as `day01::Day01`
+    items.insert(
+        0,
+        syn::parse_quote!(#[allow(unused_imports)] pub(super) use super::#day_ident;),
+    );
+    let vis = &module.vis;
+    Ok(quote!(
+        #vis struct #day_ident;
+
+        impl aoc_framework::Day for #day_ident {
+            const YEAR: u16 = #year;
+            const N: u8 = #day;
+            const EXAMPLE: Option<&'static str> = #example;
+            const PART2_EXAMPLE: Option<&'static str> = #example2;
+            type Part1 = #part1;
+            type Part2 = #part2;
+        }
+
+        #module
+    ))
+}
+
+/// Implement `Day` for the `#[aoc]` functions of a module, as a type named after the module:
+/// `#[aoc_day(2023, 1, example = "...")] mod day01 { ... }` defines `Day01`
+#[proc_macro_attribute]
+pub fn aoc_day(attr: TokenStream, input: TokenStream) -> TokenStream {
+    let module = parse_macro_input!(input as ItemMod);
+    let args = parse_macro_input!(attr with Punctuated<Expr, Token![,]>::parse_terminated);
+    impl_day_mod(module, args)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+fn impl_main(args: Punctuated<Expr, Token![,]>) -> syn::Result<proc_macro2::TokenStream> {
+    let (mut year, mut days) = (None, None);
+    for arg in &args {
+        let Expr::Assign(assign) = arg else {
+            return Err(syn::Error::new(
+                arg.span(),
+                "expected `year = ...` or `days = [...]`",
+            ));
+        };
+        match (assign.left.as_ref(), assign.right.as_ref()) {
+            (Expr::Path(left), right) if left.path.is_ident("year") => year = Some(right),
+            (Expr::Path(left), Expr::Array(array)) if left.path.is_ident("days") => {
+                days = Some(&array.elems)
+            }
+            (Expr::Path(left), right) if left.path.is_ident("days") => {
+                return Err(syn::Error::new(
+                    right.span(),
+                    "expected the modules of the days, e.g. [day01, day02]",
+                ))
+            }
+            (left, _) => {
+                return Err(syn::Error::new(
+                    left.span(),
+                    "unknown attribute, expected one of \"year\", \"days\"",
+                ))
+            }
+        }
+    }
+    let (Some(year), Some(days)) = (year, days) else {
+        return Err(syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "expected the year and the days, e.g. aoc_main!(year = 2024, days = [day01])",
+        ));
+    };
+    let (days, wrong_year): (Vec<_>, Vec<_>) = days
+        .iter()
+        .map(|day| match day {
+            Expr::Path(path) => {
+                let module = &path.path.segments.last().unwrap().ident;
+                let ident = day_ident(module);
+                Ok((
+                    quote!(#path::#ident),
+                    format!("{module} is not a day of {}", quote!(#year)),
+                ))
+            }
+            _ => Err(syn::Error::new(day.span(), "expected the module of a day")),
+        })
+        .collect::<syn::Result<Vec<_>>>()?
+        .into_iter()
+        .unzip();
+    Ok(quote!(
+        #(const _: () = assert!(<#days as aoc_framework::Day>::YEAR == #year, #wrong_year);)*
+
+        /// Run the days selected by the command line arguments, e.g. `3 5.1`
+        pub fn main() {
+            let filter = ::std::env::args().skip(1).collect::<Vec<_>>().join(",");
+            let checker = match aoc_framework::Checker::new(None, &filter) {
+                Ok(checker) => checker,
+                Err(e) => {
+                    eprintln!("\x1b[1;31mERR\x1b[0m {e}");
+                    ::std::process::exit(1);
+                }
+            };
+            checker
+                #(.run::<#days>())*
+                .warn_missing_days(#year, &[#(<#days as aoc_framework::Day>::N),*]);
+        }
+    ))
+}
+
+/// Generate the `main` of a year, running the given days filtered by the command line arguments,
+/// e.g. `aoc_main!(year = 2024, days = [day01, day02])` runs `day01::Day01` and `day02::Day02`
+#[proc_macro]
+pub fn aoc_main(input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(input with Punctuated<Expr, Token![,]>::parse_terminated);
+    impl_main(args)
         .unwrap_or_else(|e| e.to_compile_error())
         .into()
 }
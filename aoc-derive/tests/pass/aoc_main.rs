@@ -0,0 +1,43 @@
+use std::process::Command;
+
+use aoc_framework::aoc_day;
+
+#[aoc_day(2024, 1)]
+mod day01 {
+    use aoc_framework::aoc;
+
+    #[aoc(part = 1)]
+    fn first(input: &str) -> usize {
+        input.len()
+    }
+}
+
+mod year {
+    aoc_framework::aoc_main!(year = 2024, days = [super::day01]);
+}
+
+/// Run `main` of the year with `args` as the filter, returns what it printed to stderr
+fn run(args: &[&str]) -> String {
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args(args)
+        .env_remove("AOC_INPUT")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stderr).unwrap()
+}
+
+fn main() {
+    if std::env::args().len() > 1 {
+        return year::main();
+    }
+    // the days selected by the filter are the only ones reported, nothing is run or fetched
+    let stderr = run(&["3"]);
+    assert!(stderr.contains("Day 3 not implemented"));
+    assert!(!stderr.contains("Day 1") && !stderr.contains("Day 4"));
+
+    let stderr = run(&["1.2", "4.1"]);
+    assert!(stderr.contains("Day 1 part 2 not implemented"));
+    assert!(stderr.contains("Day 4 not implemented"));
+    assert!(!stderr.contains("Day 3") && !stderr.contains("2024-12-01"));
+}
@@ -1,6 +1,6 @@
 use aoc_base::{anyhow, impl_day};
 
-use aoc_derive::aoc;
+use aoc_derive::{aoc, aoc_day};
 
 struct Day01;
 
@@ -16,7 +16,257 @@ fn day1_part2(_input: Vec<u8>) -> String {
     todo!()
 }
 
-#[aoc(part = 3)]
+#[aoc(part = 2)]
 fn test_str(_input: &str) -> u64 {
     0
 }
+
+#[test]
+fn part_numbers() {
+    use aoc_framework::{Day, Part};
+
+    assert_eq!(<<Day01 as Day>::Part1 as Part>::N, 1);
+    assert_eq!(<<Day01 as Day>::Part2 as Part>::N, 2);
+    assert_eq!(<test_str as Part>::N, 2);
+}
+
+struct Day02;
+
+impl_day!(Day02::day2_part1: 2023[2], "1\n2\n3");
+
+#[aoc(part = 1, example = 6, test = Day02)]
+fn day2_part1(input: impl Iterator<Item = String>) -> u64 {
+    input.map(|ln| ln.parse::<u64>().unwrap()).sum()
+}
+
+struct Day03;
+
+impl_day!(Day03::{day3_part1, day3_part2}: 2023[3], "1 2\n3 4");
+
+#[aoc(parse = Day03)]
+fn day3_parse(input: &str) -> anyhow::Result<Vec<Vec<u64>>> {
+    input
+        .lines()
+        .map(|ln| ln.split(' ').map(|n| Ok(n.parse()?)).collect())
+        .collect()
+}
+
+#[aoc(part = 1, parse = Day03, example = 10, test = Day03)]
+fn day3_part1(rows: &[Vec<u64>]) -> u64 {
+    rows.iter().flatten().sum()
+}
+
+// the parsed input taken exactly as the parser returns it
+#[aoc(part = 2, parse = Day03, example = 5, test = Day03, benchmark = 10)]
+#[allow(clippy::ptr_arg)]
+fn day3_part2(rows: &Vec<Vec<u64>>, total: aoc_base::Answer) -> u64 {
+    let aoc_base::Answer::Num(total) = total else {
+        unreachable!()
+    };
+    total / rows.len() as u64
+}
+
+struct Day04;
+
+impl_day!(Day04::{day4::Part1, day4::Part2}: 2023[4], "3\n1\n2");
+
+#[aoc(part = "both", example = (6, "3"), test = Day04)]
+fn day4(input: Vec<String>) -> (u64, String) {
+    let nums = input.iter().map(|ln| ln.parse::<u64>().unwrap());
+    (nums.clone().sum(), nums.max().unwrap().to_string())
+}
+
+struct Day05;
+
+impl_day!(Day05::day5_part1: 2023[5], "#..\n.#.\n..#");
+
+/// Counts the cells of the diagonal of a square, 3 wide in the example
+#[aoc(part = 1, example = 3, test = Day05, param(example = 3, real = 100))]
+fn day5_part1(input: Vec<String>, size: usize) -> u64 {
+    aoc_framework::debug!("{size}x{size} square");
+    (0..size)
+        .filter(|&i| input.get(i).and_then(|ln| ln.as_bytes().get(i)) == Some(&b'#'))
+        .count() as u64
+}
+
+#[test]
+fn param_in_worker_thread() {
+    use aoc_framework::{Answer, Part, Run, SharedInput};
+
+    // the parameter is passed by the caller, not read from the thread
+    let input = "#..\n.#.\n..#\n...#";
+    let run = |run| day5_part1::run_with(input, &SharedInput::default(), run).unwrap();
+    let example = std::thread::spawn(move || run(Run::Example))
+        .join()
+        .unwrap();
+    assert_eq!(example, Answer::Num(3));
+    assert_eq!(run(Run::Real), Answer::Num(4));
+}
+
+#[aoc(part = 2, parse = Day03, benchmark = 10, param(example = 1, real = 2))]
+fn parsed_scaled(rows: &[Vec<u64>], total: aoc_base::Answer, factor: u64) -> u64 {
+    let aoc_base::Answer::Num(total) = total else {
+        unreachable!()
+    };
+    total * factor + rows.len() as u64
+}
+
+#[aoc(part = "both", param(example = 1, real = 2))]
+fn both_scaled(input: &str, factor: usize) -> (usize, usize) {
+    (input.len() * factor, factor)
+}
+
+#[test]
+fn param_of_parsed_and_both() {
+    use aoc_framework::{Answer, Part, Run, SharedInput};
+
+    let run = |run| parsed_scaled::run_with("1 2\n3 4", &SharedInput::default(), run).unwrap();
+    assert_eq!(run(Run::Example), Answer::Num(12));
+    assert_eq!(run(Run::Real), Answer::Num(22));
+    let shared = SharedInput::default();
+    assert_eq!(
+        both_scaled::Part2::run_with("abc", &shared, Run::Example).unwrap(),
+        Answer::Num(1)
+    );
+    assert_eq!(
+        both_scaled::Part1::run_with("abc", &shared, Run::Example).unwrap(),
+        Answer::Num(3)
+    );
+}
+
+struct Day06;
+
+impl_day!(Day06::{day6_part1, day6_part2}: 2023[6], "1\n2\n\nab\ncd\n", "#.\n.#\n##");
+
+#[aoc(part = 1, example = 5, test = Day06)]
+fn day6_part1((numbers, words): (Vec<u64>, Vec<String>)) -> u64 {
+    numbers.iter().sum::<u64>() + words.len() as u64
+}
+
+#[aoc(part = 2, example = 4, test = Day06)]
+fn day6_part2(grid: aoc_framework::grid::Grid<u8, 2>) -> u64 {
+    grid.iter().filter(|&&b| b == b'#').count() as u64
+}
+
+#[aoc(part = 1)]
+fn bytes(_input: &[u8]) -> u64 {
+    0
+}
+
+#[aoc(part = 1)]
+fn byte_lines(_input: Vec<Vec<u8>>) -> u64 {
+    0
+}
+
+#[aoc(part = 1)]
+fn str_lines<'a>(input: impl Iterator<Item = &'a str>) -> u64 {
+    input.count() as u64
+}
+
+#[aoc(part = 1)]
+fn numbers(input: impl Iterator<Item = i64>) -> u64 {
+    input.sum::<i64>() as u64
+}
+
+#[aoc(part = 1)]
+fn number_vec(input: Vec<u32>) -> u64 {
+    input.len() as u64
+}
+
+#[aoc(part = 1)]
+fn sections(input: Vec<Vec<String>>) -> u64 {
+    input.len() as u64
+}
+
+#[aoc(part = 1)]
+fn str_vec(input: Vec<&str>) -> u64 {
+    input.len() as u64
+}
+
+#[aoc(part = 1)]
+fn byte_slices<'a>(input: impl Iterator<Item = &'a [u8]>) -> u64 {
+    input.map(|line| line.len() as u64).sum()
+}
+
+#[aoc(part = 1)]
+fn input_sections(input: aoc_base::input::Input) -> usize {
+    input.sections().count()
+}
+
+#[test]
+fn borrowed_input() {
+    use aoc_base::{input::Input, Answer, Part};
+
+    let input = Input::new("1 2\n\n\n3\n");
+    assert_eq!(input_sections::run_input(input).unwrap(), Answer::Num(2));
+    assert_eq!(byte_slices::run_input(input).unwrap(), Answer::Num(4));
+    assert_eq!(str_vec::run(input.reader()).unwrap(), Answer::Num(4));
+}
+
+struct Day07;
+
+impl_day!(Day07::{day7_part1, day7_part2}: 2023[7], "3\n-4\n5");
+
+#[aoc(part = 1, example = 4, test = Day07)]
+fn day7_part1(input: impl Iterator<Item = i64>) -> anyhow::Result<Option<i64>> {
+    Ok(Some(input.sum()))
+}
+
+#[aoc(part = 2, example = "-4", test = Day07)]
+fn day7_part2(input: Vec<i32>) -> Option<i32> {
+    input.into_iter().min()
+}
+
+#[aoc(part = 1)]
+fn returns_usize(input: &str) -> usize {
+    input.len()
+}
+
+#[aoc(part = 1)]
+fn returns_char(_input: &str) -> char {
+    'x'
+}
+
+#[aoc(part = 1)]
+fn returns_static_str(_input: &str) -> &'static str {
+    "abc"
+}
+
+#[aoc(part = 1)]
+fn returns_display(_input: &str) -> std::net::Ipv4Addr {
+    std::net::Ipv4Addr::LOCALHOST
+}
+
+#[aoc(part = 1)]
+fn returns_string_error(input: &str) -> Result<u32, String> {
+    input.parse().map_err(|_| format!("{input:?} is not a number"))
+}
+
+#[aoc(part = "both")]
+fn returns_options(_input: &str) -> (Option<u64>, Result<String, std::fmt::Error>) {
+    (None, Ok(String::new()))
+}
+
+#[aoc_day(2023, 8, example = "1\n2\n3", example2 = include_str!("fixtures/day08_example2.txt"))]
+mod day08 {
+    use aoc_framework::aoc;
+
+    #[aoc(part = 1, example = 6, test = Day08)]
+    fn total(input: impl Iterator<Item = u64>) -> u64 {
+        input.sum()
+    }
+
+    #[aoc(part = 2, example = 4, test = Day08)]
+    fn count(input: Vec<String>) -> usize {
+        input.len()
+    }
+}
+
+#[test]
+fn aoc_day() {
+    use aoc_framework::{Day, Part};
+
+    assert_eq!((Day08::YEAR, Day08::N), (2023, 8));
+    assert_eq!(<<Day08 as Day>::Part2 as Part>::N, 2);
+    assert_eq!(Day08::PART2_EXAMPLE, Some("a\nb\nc\nd\n"));
+}
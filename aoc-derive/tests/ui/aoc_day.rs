@@ -0,0 +1,34 @@
+use aoc_framework::aoc_day;
+
+#[aoc_day(2023, 1, exmaple = "1")]
+mod day01 {}
+
+#[aoc_day(2023, 2)]
+mod day02 {
+    use aoc_framework::aoc;
+
+    #[aoc(part = "both")]
+    fn both(input: &str) -> (usize, usize) {
+        (input.len(), 0)
+    }
+
+    #[aoc(part = 2)]
+    fn second(input: &str) -> usize {
+        input.len()
+    }
+}
+
+#[aoc_day(2023)]
+mod day03 {}
+
+#[aoc_day(2023, 4)]
+mod day04 {
+    use aoc_framework::aoc;
+
+    #[aoc(part = 3)]
+    fn third(input: &str) -> usize {
+        input.len()
+    }
+}
+
+fn main() {}
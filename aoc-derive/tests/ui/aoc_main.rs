@@ -0,0 +1,16 @@
+use aoc_framework::{aoc_day, aoc_main};
+
+#[aoc_day(2024, 1)]
+mod day01 {
+    use aoc_framework::aoc;
+
+    #[aoc(part = 1)]
+    fn first(input: &str) -> usize {
+        input.len()
+    }
+}
+
+#[aoc_day(2023, 2)]
+mod day02 {}
+
+aoc_main!(year = 2024, days = [day01, day02]);
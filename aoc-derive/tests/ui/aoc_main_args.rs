@@ -0,0 +1,9 @@
+mod first {
+    aoc_framework::aoc_main!(year = 2024, days = day01);
+}
+
+mod second {
+    aoc_framework::aoc_main!(year = 2024, dyas = []);
+}
+
+fn main() {}
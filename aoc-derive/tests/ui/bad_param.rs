@@ -0,0 +1,8 @@
+use aoc_framework::aoc;
+
+#[aoc(part = 1, param(example = 7, rael = 71))]
+fn solve(input: &str, size: usize) -> usize {
+    input.len() + size
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use aoc_framework::{aoc, impl_day};
+
+struct Day01;
+
+impl_day!(Day01::{first, second}: 2023[1]);
+
+#[aoc(part = 1)]
+fn first(input: &str) -> usize {
+    input.len()
+}
+
+#[aoc(part = 1)]
+fn second(input: &str) -> usize {
+    input.len()
+}
+
+fn main() {}
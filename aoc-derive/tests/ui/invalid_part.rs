@@ -0,0 +1,8 @@
+use aoc_framework::aoc;
+
+#[aoc(part = 3)]
+fn solve(input: &str) -> usize {
+    input.len()
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use aoc_framework::aoc;
+
+#[aoc(part = "one")]
+fn solve(input: &str) -> usize {
+    input.len()
+}
+
+#[aoc(part = 1, benchmark = 1.5)]
+fn solve_bench(input: &str) -> usize {
+    input.len()
+}
+
+#[aoc(part = "both", benchmark = 10)]
+fn solve_both(input: &str) -> (usize, usize) {
+    (input.len(), 0)
+}
+
+fn main() {}
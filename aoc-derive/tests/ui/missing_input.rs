@@ -0,0 +1,8 @@
+use aoc_framework::aoc;
+
+#[aoc(part = 1)]
+fn solve() -> u64 {
+    0
+}
+
+fn main() {}
@@ -0,0 +1,35 @@
+use aoc_framework::aoc;
+
+#[aoc(part = 1)]
+fn input_type(_input: std::collections::HashMap<u8, u8>) -> u64 {
+    0
+}
+
+mod my {
+    pub struct Grid<T, const N: usize>(T);
+}
+
+#[aoc(part = 1)]
+fn other_grid(_input: my::Grid<u8, 2>) -> u64 {
+    0
+}
+
+#[aoc(part = 1)]
+fn grid_of_chars(_input: aoc_framework::grid::Grid<char, 2>) -> u64 {
+    0
+}
+
+#[aoc(part = 1)]
+fn no_answer(_input: &str) {}
+
+#[aoc(part = 1)]
+fn tuple_answer(_input: &str) -> (u64, u64) {
+    (0, 0)
+}
+
+#[aoc(part = 1, example = (1, 2))]
+fn tuple_example(_input: &str) -> u64 {
+    0
+}
+
+fn main() {}
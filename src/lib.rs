@@ -4,11 +4,17 @@ pub use itertools::Itertools;
 
 pub use aoc_base::{
     Answer::{self, *},
-    Day, Part,
-    checker::Checker,
-    impl_day,
+    Day, ParsedDay, Part,
+    checker::{Checker, CustomInput},
+    debug,
+    error::CheckerError,
+    impl_day, input,
+    param::{Param, Run, is_example},
+    shared::SharedInput,
+    source::{LocalSource, PuzzleSource},
+    testing,
 };
-pub use aoc_derive::aoc;
+pub use aoc_derive::{aoc, aoc_day, aoc_main};
 
 pub mod bcd;
 pub mod direction;